@@ -0,0 +1,95 @@
+//! Shared, bounded-concurrency refresh pipeline for tabs that need to re-fetch every tracked
+//! series' episode list.
+//!
+//! My Shows, Watchlist and Statistics each used to refresh one series at a time, serializing a
+//! lot of otherwise-independent network round-trips. This fans out over the tracked ids with a
+//! configurable concurrency limit and reports incremental progress so a tab can show something
+//! like "refreshing 12/48" instead of blocking until everything is done.
+
+use iced::futures::channel::mpsc;
+use iced::futures::sink::SinkExt;
+use iced::futures::{stream, StreamExt};
+use iced::subscription::{self, Subscription};
+
+use crate::core::caching::episode_list::EpisodeList;
+use crate::core::settings_config::refresh_settings;
+
+/// Used when the user hasn't configured a concurrency limit
+const DEFAULT_CONCURRENCY: usize = 5;
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Ready(mpsc::Sender<Input>),
+    Progress { completed: usize, total: usize },
+    Finished,
+}
+
+#[derive(Debug, Clone)]
+pub enum Input {
+    Refresh(Vec<u32>),
+}
+
+enum State {
+    Starting,
+    Ready(mpsc::Receiver<Input>),
+}
+
+/// One-shot variant for call sites using `Command::perform` rather than a subscription - refreshes
+/// every given series' episode list cache with the same bounded concurrency and returns once all
+/// of them have completed, without incremental progress.
+pub async fn refresh_all(series_ids: Vec<u32>) -> usize {
+    let concurrency = refresh_settings::get_concurrent_refresh_limit_from_settings()
+        .unwrap_or(DEFAULT_CONCURRENCY);
+
+    stream::iter(series_ids)
+        .map(EpisodeList::new)
+        .buffer_unordered(concurrency)
+        .count()
+        .await
+}
+
+pub fn bulk_refresh() -> Subscription<Event> {
+    subscription::channel("bulk-series-refresh", 100, |mut output| async move {
+        let mut state = State::Starting;
+
+        loop {
+            match &mut state {
+                State::Starting => {
+                    let (sender, receiver) = mpsc::channel(100);
+                    output
+                        .send(Event::Ready(sender))
+                        .await
+                        .expect("failed to send bulk refresh input sender");
+                    state = State::Ready(receiver);
+                }
+                State::Ready(receiver) => {
+                    let Input::Refresh(series_ids) = receiver.select_next_some().await;
+                    let total = series_ids.len();
+                    let concurrency =
+                        refresh_settings::get_concurrent_refresh_limit_from_settings()
+                            .unwrap_or(DEFAULT_CONCURRENCY);
+
+                    let mut completed = 0;
+                    let mut results = stream::iter(series_ids)
+                        .map(EpisodeList::new)
+                        .buffer_unordered(concurrency);
+
+                    while results.next().await.is_some() {
+                        completed += 1;
+                        output
+                            .send(Event::Progress { completed, total })
+                            .await
+                            .expect("failed to send bulk refresh progress");
+                    }
+
+                    output
+                        .send(Event::Finished)
+                        .await
+                        .expect("failed to send bulk refresh completion");
+
+                    state = State::Starting;
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,27 @@
+//! Ad-hoc discovery feeds shown as alternative underlays in `DiscoverTab` alongside the full
+//! schedule. Unlike the full schedule these aren't written to the on-disk cache: they're more
+//! about "what's interesting right now" than a stable dataset worth keeping around between runs.
+
+use crate::core::api::tv_maze::series_information::SeriesMainInformation;
+use crate::core::api::tv_maze::shows;
+
+/// TVmaze's globally trending/most-tracked shows
+pub async fn get_trending_series() -> Vec<SeriesMainInformation> {
+    shows::get_trending().await.unwrap_or_default()
+}
+
+/// A fixed default genre until `DiscoverTab` grows a sub-selector for it
+const DEFAULT_GENRE: &str = "Drama";
+
+pub async fn get_series_by_genre() -> Vec<SeriesMainInformation> {
+    shows::get_by_genre(DEFAULT_GENRE).await.unwrap_or_default()
+}
+
+/// A fixed default network until `DiscoverTab` grows a sub-selector for it
+const DEFAULT_NETWORK: &str = "Netflix";
+
+pub async fn get_series_by_network() -> Vec<SeriesMainInformation> {
+    shows::get_by_network(DEFAULT_NETWORK)
+        .await
+        .unwrap_or_default()
+}
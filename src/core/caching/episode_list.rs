@@ -7,8 +7,10 @@ use crate::core::{
         ApiError,
     },
     caching::CACHER,
+    settings_config::time_settings,
 };
 use chrono::{DateTime, Datelike, Local, Timelike, Utc};
+use chrono_tz::Tz;
 use tracing::info;
 
 use super::{read_cache, write_cache, CacheFilePath};
@@ -113,11 +115,8 @@ impl EpisodeList {
     /// This method returns an optional bool as an episode my not have airstamp associated with it hence
     /// the method can not infer that information.
     pub fn is_episode_watchable(episode: &Episode) -> Option<bool> {
-        let airstamp = DateTime::parse_from_rfc3339(episode.airstamp.as_ref()?)
-            .unwrap()
-            .with_timezone(&Local);
-        let local_time = Utc::now().with_timezone(&Local);
-        Some(airstamp <= local_time)
+        let airstamp = DateTime::parse_from_rfc3339(episode.airstamp.as_ref()?).unwrap();
+        Some(airstamp <= Utc::now())
     }
 
     /// Returns the previous episode from the current time
@@ -180,15 +179,58 @@ impl TotalEpisodes {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq)]
+/// The timezone an [`EpisodeReleaseTime`] is displayed in
+///
+/// Resolved once from [`time_settings`] at construction time so that a release time keeps
+/// displaying in the zone the user had configured when it was created, even if the setting
+/// changes afterwards.
+#[derive(Debug, Clone, Copy)]
+enum DisplayZone {
+    /// The system's local timezone, used when no zone has been configured or the configured
+    /// IANA name failed to parse
+    Local,
+    Zone(Tz),
+}
+
+impl DisplayZone {
+    fn from_settings() -> Self {
+        time_settings::get_timezone_from_settings()
+            .map(Self::Zone)
+            .unwrap_or(Self::Local)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct EpisodeReleaseTime {
-    release_time: DateTime<Local>,
+    release_time: DateTime<Utc>,
+    display_zone: DisplayZone,
+}
+
+impl PartialEq for EpisodeReleaseTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.release_time == other.release_time
+    }
+}
+
+impl Eq for EpisodeReleaseTime {}
+
+impl PartialOrd for EpisodeReleaseTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EpisodeReleaseTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.release_time.cmp(&other.release_time)
+    }
 }
 
 impl EpisodeReleaseTime {
     pub fn new(release_time: DateTime<Utc>) -> Self {
         Self {
-            release_time: release_time.with_timezone(&Local),
+            release_time,
+            display_zone: DisplayZone::from_settings(),
         }
     }
 
@@ -196,30 +238,32 @@ impl EpisodeReleaseTime {
         Self {
             release_time: DateTime::parse_from_rfc3339(str)
                 .unwrap()
-                .with_timezone(&Local),
+                .with_timezone(&Utc),
+            display_zone: DisplayZone::from_settings(),
         }
     }
 
+    /// Returns the release instant in UTC, regardless of the configured display timezone
+    pub fn utc(&self) -> DateTime<Utc> {
+        self.release_time
+    }
+
+    /// Tells if the release is still in the future but falls within `window` of it, useful for
+    /// firing "airs soon" pre-alerts
+    pub fn is_within(&self, window: chrono::Duration) -> bool {
+        let now = Utc::now();
+        self.release_time > now && self.release_time - now <= window
+    }
+
     /// Returns the remaining time for an episode to be released
+    ///
+    /// The result is a compound string (e.g. "1 week 2 days") decomposed into weeks, days, hours
+    /// and minutes, or `None` if `self` has already been released.
     pub fn get_remaining_release_time(&self) -> Option<String> {
-        let local_time = Utc::now().with_timezone(&Local);
-
-        if self.release_time > local_time {
-            let time_diff = self.release_time - local_time;
+        let now = Utc::now();
 
-            if time_diff.num_weeks() != 0 {
-                return Some(format!("{} weeks", time_diff.num_weeks()));
-            }
-            if time_diff.num_days() != 0 {
-                return Some(format!("{} days", time_diff.num_days()));
-            }
-            if time_diff.num_hours() != 0 {
-                return Some(format!("{} hours", time_diff.num_hours()));
-            }
-            if time_diff.num_minutes() != 0 {
-                return Some(format!("{} minutes", time_diff.num_minutes()));
-            }
-            Some(String::from("Now"))
+        if self.release_time > now {
+            Some(format_compound_duration(self.release_time - now))
         } else {
             None
         }
@@ -227,55 +271,174 @@ impl EpisodeReleaseTime {
 
     /// Returns the remaining full date and time for an episode to be released
     pub fn get_full_release_date_and_time(&self) -> String {
-        /// appends zero the minute digit if it's below 10 for better display
-        fn append_zero(num: u32) -> String {
-            if num < 10 {
-                format!("0{num}")
-            } else {
-                format!("{num}")
-            }
+        match self.display_zone {
+            DisplayZone::Local => format_date_and_time(self.release_time.with_timezone(&Local)),
+            DisplayZone::Zone(tz) => format_date_and_time(self.release_time.with_timezone(&tz)),
         }
+    }
+}
 
-        let (is_pm, hour) = self.release_time.hour12();
-        let pm_am = if is_pm { "p.m." } else { "a.m." };
+/// Formats a date and time the way [`EpisodeReleaseTime::get_full_release_date_and_time`] displays it,
+/// regardless of which timezone `date_time` is expressed in
+fn format_date_and_time(date_time: DateTime<impl chrono::TimeZone>) -> String {
+    /// appends zero the minute digit if it's below 10 for better display
+    fn append_zero(num: u32) -> String {
+        if num < 10 {
+            format!("0{num}")
+        } else {
+            format!("{num}")
+        }
+    }
 
-        let minute = append_zero(self.release_time.minute());
+    let (is_pm, hour) = date_time.hour12();
+    let pm_am = if is_pm { "p.m." } else { "a.m." };
 
-        format!(
-            "{} {} {}:{} {}",
-            self.release_time.date_naive(),
-            self.release_time.weekday(),
-            hour,
-            minute,
-            pm_am
-        )
-    }
+    let minute = append_zero(date_time.minute());
+
+    format!(
+        "{} {} {}:{} {}",
+        date_time.date_naive(),
+        date_time.weekday(),
+        hour,
+        minute,
+        pm_am
+    )
 }
 
 /// Returns the remaining time for an episode to be released
 pub fn get_release_remaining_time(episode: &Episode) -> Option<String> {
-    let airstamp = DateTime::parse_from_rfc3339(episode.airstamp.as_ref()?)
-        .unwrap()
-        .with_timezone(&Local);
-    let local_time = Utc::now().with_timezone(&Local);
+    EpisodeReleaseTime::from_rfc3339_str(episode.airstamp.as_ref()?).get_remaining_release_time()
+}
 
-    if airstamp > local_time {
-        let time_diff = airstamp - local_time;
+/// Decomposes a duration into its week, day, hour and minute components
+fn decompose_duration(duration: chrono::Duration) -> (i64, i64, i64, i64) {
+    let weeks = duration.num_weeks();
+    let days = duration.num_days() - weeks * 7;
+    let hours = duration.num_hours() - duration.num_days() * 24;
+    let minutes = duration.num_minutes() - duration.num_hours() * 60;
+    (weeks, days, hours, minutes)
+}
 
-        if time_diff.num_weeks() != 0 {
-            return Some(format!("{} weeks", time_diff.num_weeks()));
-        }
-        if time_diff.num_days() != 0 {
-            return Some(format!("{} days", time_diff.num_days()));
-        }
-        if time_diff.num_hours() != 0 {
-            return Some(format!("{} hours", time_diff.num_hours()));
-        }
-        if time_diff.num_minutes() != 0 {
-            return Some(format!("{} minutes", time_diff.num_minutes()));
-        }
-        Some(String::from("Now"))
-    } else {
-        None
+/// Formats a positive duration as a compound, human readable string (e.g. "1 week 2 days",
+/// "5 hours 12 minutes"), keeping only the two most significant non-zero components.
+///
+/// Respects a user-configured format template (a `%w`/`%d`/`%h`/`%m` placeholder language) when
+/// one is set, falling back to this default compound string otherwise. Sub-minute durations
+/// collapse to "Now".
+fn format_compound_duration(duration: chrono::Duration) -> String {
+    if duration.num_minutes() < 1 {
+        return String::from("Now");
+    }
+
+    if let Some(template) = time_settings::get_countdown_format_from_settings() {
+        return format_duration_with_template(&template, duration);
+    }
+
+    let (weeks, days, hours, minutes) = decompose_duration(duration);
+    [
+        (weeks, "week", "weeks"),
+        (days, "day", "days"),
+        (hours, "hour", "hours"),
+        (minutes, "minute", "minutes"),
+    ]
+    .into_iter()
+    .filter(|(value, _, _)| *value != 0)
+    .take(2)
+    .map(|(value, singular, plural)| {
+        format!("{} {}", value, if value == 1 { singular } else { plural })
+    })
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// Fills in a `%w`/`%d`/`%h`/`%m` format template with a duration's components, eliding
+/// zero-valued leading units so e.g. a sub-day duration doesn't render "0 weeks 0 days 5 hours".
+/// Drops whole whitespace-separated words that hold a leading zero placeholder (not just the
+/// placeholder itself), so a template like `"%dd %hh"` with `days == 0` renders "5h" rather than
+/// leaving the literal "d" stranded behind a blank placeholder. The placeholder-free words that
+/// follow a dropped placeholder, up to the next placeholder, are dropped with it, so a separate
+/// unit label like the "days" in `"%d days %h hours"` goes too.
+fn format_duration_with_template(template: &str, duration: chrono::Duration) -> String {
+    let (weeks, days, hours, minutes) = decompose_duration(duration);
+    let components = [("%w", weeks), ("%d", days), ("%h", hours), ("%m", minutes)];
+
+    let mut seen_nonzero = false;
+    let mut dropping_unit = false;
+    template
+        .split_whitespace()
+        .filter_map(|word| {
+            let Some(&(placeholder, value)) =
+                components.iter().find(|(placeholder, _)| word.contains(placeholder))
+            else {
+                return (!dropping_unit).then(|| word.to_string());
+            };
+
+            if value == 0 && !seen_nonzero {
+                dropping_unit = true;
+                return None;
+            }
+            dropping_unit = false;
+            seen_nonzero = true;
+            Some(word.replace(placeholder, &value.to_string()))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decompose_duration, format_compound_duration, format_duration_with_template};
+    use chrono::Duration;
+
+    #[test]
+    fn decompose_duration_splits_into_weeks_days_hours_minutes() {
+        let duration = Duration::weeks(1) + Duration::days(2) + Duration::hours(3) + Duration::minutes(4);
+        assert_eq!(decompose_duration(duration), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn decompose_duration_handles_sub_minute_durations() {
+        assert_eq!(decompose_duration(Duration::seconds(30)), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn format_compound_duration_collapses_sub_minute_to_now() {
+        assert_eq!(format_compound_duration(Duration::seconds(59)), "Now");
+    }
+
+    #[test]
+    fn format_compound_duration_keeps_only_two_most_significant_units() {
+        let duration = Duration::weeks(1) + Duration::days(2) + Duration::hours(3) + Duration::minutes(4);
+        assert_eq!(format_compound_duration(duration), "1 week 2 days");
+    }
+
+    #[test]
+    fn format_compound_duration_pluralizes_correctly() {
+        assert_eq!(format_compound_duration(Duration::hours(1)), "1 hour");
+        assert_eq!(format_compound_duration(Duration::hours(2)), "2 hours");
+    }
+
+    #[test]
+    fn format_duration_with_template_elides_leading_zero_units() {
+        let duration = Duration::hours(5) + Duration::minutes(30);
+        assert_eq!(
+            format_duration_with_template("%w weeks %d days %h hours %m minutes", duration),
+            "5 hours 30 minutes"
+        );
+    }
+
+    #[test]
+    fn format_duration_with_template_drops_the_whole_word_not_just_the_placeholder() {
+        let duration = Duration::hours(5);
+        assert_eq!(format_duration_with_template("%dd %hh", duration), "5h");
+    }
+
+    #[test]
+    fn format_duration_with_template_keeps_a_zero_unit_once_a_higher_one_is_nonzero() {
+        let duration = Duration::weeks(1) + Duration::minutes(5);
+        assert_eq!(
+            format_duration_with_template("%w %d %h %m", duration),
+            "1 0 0 5"
+        );
     }
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+//! Exports the upcoming episode schedule, aggregated across every tracked series, as a standard
+//! feed consumable by external calendar and feed-reader tools.
+//!
+//! The schedule is built from the same per-series "next episode" data the GUI already computes
+//! via [`EpisodeList::get_next_episode_and_time`]; this just recasts it as an outbound feed
+//! instead of keeping it inside the GUI.
+
+use std::path::Path;
+
+use crate::core::caching::episode_list::EpisodeList;
+use crate::core::database;
+use crate::core::import_export::xml_escape;
+
+#[derive(Debug)]
+pub enum FeedExportError {
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for FeedExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for FeedExportError {}
+
+impl From<std::io::Error> for FeedExportError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+struct ScheduledEpisode {
+    series_name: String,
+    season: u32,
+    episode: u32,
+    episode_name: String,
+    airstamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Walks the tracked series collecting each one's next unaired episode, sorted soonest-first
+async fn collect_upcoming_episodes() -> Vec<ScheduledEpisode> {
+    let mut scheduled = Vec::new();
+
+    for series in database::DB.get_series_collection() {
+        let Ok(episode_list) = EpisodeList::new(series.id).await else {
+            continue;
+        };
+
+        if let Some((episode, release_time)) = episode_list.get_next_episode_and_time() {
+            if let Some(number) = episode.number {
+                scheduled.push(ScheduledEpisode {
+                    series_name: series.name.clone(),
+                    season: episode.season,
+                    episode: number,
+                    episode_name: episode.name.clone(),
+                    airstamp: release_time.utc(),
+                });
+            }
+        }
+    }
+
+    scheduled.sort_by_key(|episode| episode.airstamp);
+    scheduled
+}
+
+/// Exports the upcoming schedule as an iCalendar (`.ics`) file, one `VEVENT` per episode
+pub async fn export_ics(path: impl AsRef<Path>) -> Result<(), FeedExportError> {
+    let scheduled = collect_upcoming_episodes().await;
+
+    let mut ics = String::from(
+        "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//Series Troxide//Episode Schedule//EN\r\n",
+    );
+
+    for episode in &scheduled {
+        ics.push_str("BEGIN:VEVENT\r\n");
+        ics.push_str(&format!(
+            "UID:{}-s{}e{}@series-troxide\r\n",
+            uid_safe(&episode.series_name),
+            episode.season,
+            episode.episode
+        ));
+        ics.push_str(&format!(
+            "DTSTART:{}\r\n",
+            episode.airstamp.format("%Y%m%dT%H%M%SZ")
+        ));
+        ics.push_str(&format!(
+            "SUMMARY:S{:02}E{:02} — {}\r\n",
+            episode.season, episode.episode, episode.series_name
+        ));
+        if !episode.episode_name.is_empty() {
+            ics.push_str(&format!("DESCRIPTION:{}\r\n", episode.episode_name));
+        }
+        ics.push_str("END:VEVENT\r\n");
+    }
+
+    ics.push_str("END:VCALENDAR\r\n");
+    tokio::fs::write(path, ics).await?;
+    Ok(())
+}
+
+/// Exports the upcoming schedule as an RSS feed, one `<item>` per episode
+pub async fn export_rss(path: impl AsRef<Path>) -> Result<(), FeedExportError> {
+    let scheduled = collect_upcoming_episodes().await;
+
+    let mut rss = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <rss version=\"2.0\">\n  <channel>\n    <title>Series Troxide Schedule</title>\n",
+    );
+
+    for episode in &scheduled {
+        let title = xml_escape(&format!(
+            "S{:02}E{:02} — {}",
+            episode.season, episode.episode, episode.series_name
+        ));
+        rss.push_str("    <item>\n");
+        rss.push_str(&format!("      <title>{title}</title>\n"));
+        rss.push_str(&format!(
+            "      <pubDate>{}</pubDate>\n",
+            episode.airstamp.to_rfc2822()
+        ));
+        rss.push_str("    </item>\n");
+    }
+
+    rss.push_str("  </channel>\n</rss>\n");
+    tokio::fs::write(path, rss).await?;
+    Ok(())
+}
+
+/// Strips characters that would be awkward in an iCalendar `UID` line
+fn uid_safe(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::uid_safe;
+
+    #[test]
+    fn uid_safe_keeps_alphanumeric_characters() {
+        assert_eq!(uid_safe("Breaking Bad S01E02"), "BreakingBadS01E02");
+    }
+
+    #[test]
+    fn uid_safe_strips_punctuation_and_symbols() {
+        assert_eq!(uid_safe("Doctor Who: S2020E01 — \"Revolution\""), "DoctorWhoS2020E01Revolution");
+    }
+
+    #[test]
+    fn uid_safe_of_empty_string_is_empty() {
+        assert_eq!(uid_safe(""), "");
+    }
+}
@@ -0,0 +1,199 @@
+//! Bulk import/export of the tracked-show library, so a user can move it between machines or in
+//! from another tracker.
+//!
+//! Two formats are supported: OPML (the de-facto subscription-list format podcast apps use,
+//! carrying just a TVmaze series id and title per `<outline>`) and a richer native JSON variant
+//! that also carries watched-episode markers.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::caching::episode_list::EpisodeList;
+use crate::core::database;
+
+#[derive(Debug)]
+pub enum ImportExportError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl std::fmt::Display for ImportExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::Parse(err) => write!(f, "failed to parse library file: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ImportExportError {}
+
+impl From<std::io::Error> for ImportExportError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The native, richer export format that also carries watched-episode markers
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LibraryExport {
+    pub series: Vec<SeriesExport>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SeriesExport {
+    pub id: u32,
+    pub name: String,
+    /// `(season, episode)` pairs that have been watched
+    pub watched_episodes: Vec<(u32, u32)>,
+}
+
+/// Exports the tracked library as the native JSON variant
+pub async fn export_json(path: impl AsRef<Path>) -> Result<(), ImportExportError> {
+    let series = database::DB
+        .get_series_collection()
+        .into_iter()
+        .map(|series| SeriesExport {
+            id: series.id,
+            name: series.name.clone(),
+            watched_episodes: series.get_watched_episodes(),
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&LibraryExport { series })
+        .map_err(|err| ImportExportError::Parse(err.to_string()))?;
+
+    tokio::fs::write(path, json).await?;
+    Ok(())
+}
+
+/// Imports a native JSON library export, merging watch progress into the existing library rather
+/// than overwriting it
+pub async fn import_json(path: impl AsRef<Path>) -> Result<(), ImportExportError> {
+    let contents = tokio::fs::read_to_string(path).await?;
+    let export: LibraryExport =
+        serde_json::from_str(&contents).map_err(|err| ImportExportError::Parse(err.to_string()))?;
+
+    for series_export in export.series {
+        merge_series(
+            series_export.id,
+            series_export.name,
+            series_export.watched_episodes,
+        )
+        .await;
+    }
+    Ok(())
+}
+
+/// Exports the tracked library as an OPML subscription list
+pub async fn export_opml(path: impl AsRef<Path>) -> Result<(), ImportExportError> {
+    let mut opml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <opml version=\"2.0\">\n  <head>\n    <title>Series Troxide Library</title>\n  </head>\n  <body>\n",
+    );
+
+    for series in database::DB.get_series_collection() {
+        opml.push_str(&format!(
+            "    <outline text=\"{name}\" tvmazeId=\"{id}\" />\n",
+            name = xml_escape(&series.name),
+            id = series.id,
+        ));
+    }
+
+    opml.push_str("  </body>\n</opml>\n");
+    tokio::fs::write(path, opml).await?;
+    Ok(())
+}
+
+/// Imports an OPML subscription list, resolving each entry's episode list lazily and merging it
+/// into the existing library
+pub async fn import_opml(path: impl AsRef<Path>) -> Result<(), ImportExportError> {
+    let contents = tokio::fs::read_to_string(path).await?;
+
+    for (series_id, name) in parse_tvmaze_outlines(&contents) {
+        merge_series(series_id, name, Vec::new()).await;
+    }
+    Ok(())
+}
+
+/// Resolves a series' episode list online if not already cached, then merges the given watched
+/// episodes into its tracked watch progress without overwriting what's already there
+async fn merge_series(series_id: u32, name: String, watched_episodes: Vec<(u32, u32)>) {
+    let _ = EpisodeList::new(series_id).await;
+
+    let mut series = database::DB
+        .get_series(series_id)
+        .unwrap_or_else(|| database::Series::new(name, series_id));
+
+    for (season, episode) in watched_episodes {
+        series.add_episode_unchecked(season, episode);
+    }
+
+    // `Series::new` alone doesn't track the series; without at least one `add_episode_unchecked`
+    // call above (e.g. an OPML entry or a JSON entry with no watched episodes yet) it would never
+    // reach the database otherwise, so every import has to insert it explicitly.
+    database::DB.add_series(series);
+}
+
+fn parse_tvmaze_outlines(opml: &str) -> Vec<(u32, String)> {
+    opml.lines()
+        .filter_map(|line| {
+            let id: u32 = extract_attribute(line, "tvmazeId")?.parse().ok()?;
+            let name = extract_attribute(line, "text").unwrap_or_default();
+            Some((id, name))
+        })
+        .collect()
+}
+
+fn extract_attribute(line: &str, attribute: &str) -> Option<String> {
+    let needle = format!("{attribute}=\"");
+    let start = line.find(&needle)? + needle.len();
+    let rest = &line[start..];
+    let end = rest.find('"')?;
+    Some(rest[..end].to_owned())
+}
+
+pub(crate) fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract_attribute, parse_tvmaze_outlines};
+
+    #[test]
+    fn extract_attribute_reads_a_quoted_value() {
+        let line = r#"    <outline text="Breaking Bad" tvmazeId="169" />"#;
+        assert_eq!(extract_attribute(line, "tvmazeId").as_deref(), Some("169"));
+        assert_eq!(
+            extract_attribute(line, "text").as_deref(),
+            Some("Breaking Bad")
+        );
+    }
+
+    #[test]
+    fn extract_attribute_is_none_when_missing() {
+        let line = r#"    <outline text="Breaking Bad" />"#;
+        assert_eq!(extract_attribute(line, "tvmazeId"), None);
+    }
+
+    #[test]
+    fn parse_tvmaze_outlines_skips_entries_without_a_valid_id() {
+        let opml = "<?xml version=\"1.0\"?>\n\
+             <opml version=\"2.0\">\n  <body>\n\
+             \x20   <outline text=\"Breaking Bad\" tvmazeId=\"169\" />\n\
+             \x20   <outline text=\"No Id\" />\n\
+             \x20   <outline text=\"Bad Id\" tvmazeId=\"not-a-number\" />\n\
+             \x20 </body>\n</opml>\n";
+
+        assert_eq!(
+            parse_tvmaze_outlines(opml),
+            vec![(169, "Breaking Bad".to_string())]
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod assets;
 mod helpers;
+mod notifications;
 mod troxide_widget;
 mod view;
 
@@ -61,17 +62,38 @@ pub enum Message {
     Statistics(StatisticsMessage),
     Settings(SettingsMessage),
     Series(SeriesMessage),
+    Notifications(notifications::Message),
+}
+
+/// A single entry in the navigation stack
+///
+/// The bottom of the stack is always a `Tab`, identifying which tab root is showing underneath
+/// any pushed screens. Selecting a series pushes a `Series` route on top of it; going back pops
+/// the top route rather than unconditionally returning to the tab root, so drilling into a
+/// series from another series (e.g. a related-shows link) can be undone one screen at a time.
+enum Route {
+    Tab(TabId),
+    Series(Box<Series>),
 }
 
 pub struct TroxideGui {
-    active_tab: TabId,
-    series_view_active: bool,
+    route_stack: Vec<Route>,
     discover_tab: DiscoverTab,
     watchlist_tab: WatchlistTab,
     my_shows_tab: MyShowsTab,
     statistics_tab: StatisticsTab,
     settings_tab: SettingsTab,
-    series_view: Option<Series>,
+    notifier: notifications::Notifier,
+}
+
+impl TroxideGui {
+    /// The tab route at the bottom of the stack
+    fn active_tab(&self) -> &TabId {
+        match self.route_stack.first() {
+            Some(Route::Tab(tab_id)) => tab_id,
+            _ => unreachable!("the route stack always starts with a tab route"),
+        }
+    }
 }
 
 impl Application for TroxideGui {
@@ -82,18 +104,25 @@ impl Application for TroxideGui {
 
     fn new(flags: Self::Flags) -> (Self, iced::Command<Self::Message>) {
         let (discover_tab, discover_command) = view::discover_view::DiscoverTab::new();
+        let settings_tab = view::settings_view::SettingsTab::new(flags);
+        let (notifier, notifier_command) = notifications::Notifier::new(
+            settings_tab.get_config_settings().notifications_enabled,
+            settings_tab.get_config_settings().pre_release_notifications_enabled,
+        );
         (
             Self {
-                active_tab: TabId::Discover,
-                series_view_active: false,
+                route_stack: vec![Route::Tab(TabId::Discover)],
                 discover_tab,
                 watchlist_tab: WatchlistTab::default(),
                 statistics_tab: StatisticsTab::default(),
                 my_shows_tab: MyShowsTab::default(),
-                settings_tab: view::settings_view::SettingsTab::new(flags),
-                series_view: None,
+                settings_tab,
+                notifier,
             },
-            discover_command.map(Message::Discover),
+            Command::batch([
+                discover_command.map(Message::Discover),
+                notifier_command.map(Message::Notifications),
+            ]),
         )
     }
 
@@ -108,20 +137,25 @@ impl Application for TroxideGui {
         }
     }
 
+    fn subscription(&self) -> iced::Subscription<Message> {
+        iced::Subscription::batch([
+            self.notifier.subscription().map(Message::Notifications),
+            self.statistics_tab.subscription().map(Message::Statistics),
+        ])
+    }
+
     fn update(&mut self, message: Message) -> Command<Message> {
         if let Some((series_view, series_command)) =
-            handle_series_poster_selection(&self.active_tab, message.clone())
+            handle_series_poster_selection(self.active_tab(), message.clone())
         {
-            self.series_view = Some(series_view);
-            self.series_view_active = true;
+            self.route_stack.push(Route::Series(Box::new(series_view)));
             return series_command.map(Message::Series);
         }
 
         match message {
             Message::TabSelected(tab_id) => {
-                self.series_view_active = false;
                 let tab_id = TabId::from(tab_id);
-                self.active_tab = tab_id.clone();
+                self.route_stack = vec![Route::Tab(tab_id.clone())];
                 if let TabId::MyShows = tab_id {
                     return self.my_shows_tab.refresh().map(Message::MyShows);
                 };
@@ -139,24 +173,37 @@ impl Application for TroxideGui {
             }
             Message::MyShows(message) => self.my_shows_tab.update(message).map(Message::MyShows),
             Message::Statistics(message) => {
-                self.statistics_tab.update(message);
-                Command::none()
+                self.statistics_tab.update(message).map(Message::Statistics)
             }
             Message::Settings(message) => {
                 self.settings_tab.update(message);
+                self.notifier.set_enabled(
+                    self.settings_tab.get_config_settings().notifications_enabled,
+                    self.settings_tab
+                        .get_config_settings()
+                        .pre_release_notifications_enabled,
+                );
                 Command::none()
             }
+            Message::Notifications(message) => {
+                self.notifier.update(message).map(Message::Notifications)
+            }
             Message::Series(message) => {
-                if let Some(command) =
-                    handle_back_message_from_series(&message, &mut self.series_view_active)
-                {
-                    return command;
-                };
-                self.series_view
-                    .as_mut()
-                    .expect("for series view to send a message it must exist")
-                    .update(message)
-                    .map(Message::Series)
+                if let SeriesMessage::GoBack = message {
+                    // Always leave the bottom tab route in place; there is nothing to go back to
+                    // beyond the tab root.
+                    if self.route_stack.len() > 1 {
+                        self.route_stack.pop();
+                    }
+                    return Command::none();
+                }
+
+                match self.route_stack.last_mut() {
+                    Some(Route::Series(series)) => series.update(message).map(Message::Series),
+                    _ => unreachable!(
+                        "for a series view to send a message it must be on top of the route stack"
+                    ),
+                }
             }
         }
     }
@@ -185,18 +232,14 @@ impl Application for TroxideGui {
             ),
         ];
 
-        let active_tab_index = self.active_tab.to_owned().into();
+        let active_tab_index = self.active_tab().to_owned().into();
 
-        // Hijacking the current tab view when series view is active
-        if self.series_view_active {
+        // Rendering the top of the route stack in place of the active tab's own view, if it
+        // isn't the tab root itself
+        if let Some(Route::Series(series)) = self.route_stack.last() {
             let (_, current_view): &mut (TabLabel, Element<'_, Message, iced::Renderer>) =
                 &mut tabs[active_tab_index];
-            *current_view = self
-                .series_view
-                .as_ref()
-                .unwrap()
-                .view()
-                .map(Message::Series);
+            *current_view = series.view().map(Message::Series);
         }
 
         Tabs::with_tabs(active_tab_index, tabs, Message::TabSelected).into()
@@ -246,17 +289,6 @@ fn handle_series_poster_selection(
     None
 }
 
-fn handle_back_message_from_series(
-    series_message: &SeriesMessage,
-    series_view_active: &mut bool,
-) -> Option<Command<Message>> {
-    if let SeriesMessage::GoBack = series_message {
-        *series_view_active = false;
-        return Some(Command::none());
-    }
-    None
-}
-
 trait Tab {
     type Message;
 
@@ -0,0 +1,244 @@
+//! Periodically walks the tracked series, watching for episodes that just became watchable (or
+//! are about to), and raises a native desktop notification for each one.
+//!
+//! This runs as an [`iced::Subscription`] owned by [`super::TroxideGui`] rather than a tab, since
+//! it has to keep ticking regardless of which tab is active. It's also the *only* place that
+//! fires an aired-episode notification — `troxide_widget::episode_widget::Episode` used to raise
+//! its own, which meant a user watching an episode's poster at the moment it aired got two
+//! desktop notifications for the same event.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::caching::episode_list::EpisodeList;
+use crate::core::caching::{read_cache, write_cache, CacheFilePath, CACHER};
+use crate::core::database;
+
+/// How often the tracked series are re-checked for newly released episodes
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long before release a "airs soon" pre-alert is sent
+const PRE_ALERT_WINDOW: chrono::Duration = chrono::Duration::hours(1);
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Tick,
+    Checked(Vec<(NotifiedKey, Notification)>),
+    /// The `already_notified` set persisted from a previous run finished loading
+    PersistedLoaded(HashSet<NotifiedKey>),
+    /// An updated `already_notified` set finished writing to disk; failures are just logged, so
+    /// there's nothing in the payload
+    Persisted,
+}
+
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub series_name: String,
+    pub kind: NotificationKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NotificationKind {
+    Released,
+    AirsSoon,
+}
+
+/// A single `(series, season, episode, kind)` notification, deduplicated so a release and its
+/// pre-alert each fire at most once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotifiedKey {
+    series_id: u32,
+    season: u32,
+    episode: u32,
+    kind: NotifiedKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum NotifiedKind {
+    Released,
+    AirsSoon,
+}
+
+pub struct Notifier {
+    already_notified: HashSet<NotifiedKey>,
+    notifications_enabled: bool,
+    pre_alerts_enabled: bool,
+    /// `false` until the first [`Message::Checked`] batch has been absorbed; episodes found
+    /// already-aired on that first tick are just history (they may have aired long before this
+    /// launch), so they're recorded as seen without firing a notification for them
+    primed: bool,
+}
+
+impl Notifier {
+    /// Builds the notifier and kicks off loading the `already_notified` set a previous run
+    /// persisted, so a restart doesn't re-notify for episodes that already aired before it
+    pub fn new(notifications_enabled: bool, pre_alerts_enabled: bool) -> (Self, iced::Command<Message>) {
+        let notifier = Self {
+            already_notified: HashSet::new(),
+            notifications_enabled,
+            pre_alerts_enabled,
+            primed: false,
+        };
+        let command = iced::Command::perform(load_persisted_notified(), Message::PersistedLoaded);
+        (notifier, command)
+    }
+
+    pub fn set_enabled(&mut self, notifications_enabled: bool, pre_alerts_enabled: bool) {
+        self.notifications_enabled = notifications_enabled;
+        self.pre_alerts_enabled = pre_alerts_enabled;
+    }
+
+    pub fn subscription(&self) -> iced::Subscription<Message> {
+        if self.notifications_enabled {
+            iced::time::every(CHECK_INTERVAL).map(|_| Message::Tick)
+        } else {
+            iced::Subscription::none()
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> iced::Command<Message> {
+        match message {
+            Message::Tick => {
+                let tracked_series: Vec<(u32, String)> = database::DB
+                    .get_series_collection()
+                    .into_iter()
+                    .map(|series| (series.id, series.name.clone()))
+                    .collect();
+                let already_notified = self.already_notified.clone();
+                let pre_alerts_enabled = self.pre_alerts_enabled;
+
+                iced::Command::perform(
+                    find_new_notifications(tracked_series, already_notified, pre_alerts_enabled),
+                    Message::Checked,
+                )
+            }
+            Message::Checked(notifications) => {
+                let just_primed = !self.primed;
+                self.primed = true;
+
+                if notifications.is_empty() {
+                    return iced::Command::none();
+                }
+
+                for (key, notification) in notifications {
+                    self.already_notified.insert(key);
+                    if !just_primed {
+                        fire_desktop_notification(&notification);
+                    }
+                }
+
+                iced::Command::perform(
+                    persist_notified(self.already_notified.clone()),
+                    |_| Message::Persisted,
+                )
+            }
+            Message::PersistedLoaded(notified) => {
+                self.already_notified.extend(notified);
+                iced::Command::none()
+            }
+            Message::Persisted => iced::Command::none(),
+        }
+    }
+}
+
+/// Loads the `already_notified` set persisted by a previous run, falling back to an empty set if
+/// there isn't one yet (or it can't be read)
+async fn load_persisted_notified() -> HashSet<NotifiedKey> {
+    let path = CACHER.get_cache_file_path(CacheFilePath::NotifiedEpisodes);
+    let Ok(json_string) = read_cache(&path).await else {
+        return HashSet::new();
+    };
+    serde_json::from_str(&json_string).unwrap_or_default()
+}
+
+/// Persists the `already_notified` set so a restart doesn't re-notify for episodes already seen
+async fn persist_notified(already_notified: HashSet<NotifiedKey>) {
+    let path = CACHER.get_cache_file_path(CacheFilePath::NotifiedEpisodes);
+    match serde_json::to_string(&already_notified) {
+        Ok(json_string) => write_cache(&json_string, &path).await,
+        Err(err) => tracing::error!("failed to serialize already-notified episodes: {}", err),
+    }
+}
+
+/// Finds episodes that have just aired or are about to, skipping any `(series, season, episode,
+/// kind)` combination already present in `already_notified`
+async fn find_new_notifications(
+    tracked_series: Vec<(u32, String)>,
+    already_notified: HashSet<NotifiedKey>,
+    pre_alerts_enabled: bool,
+) -> Vec<(NotifiedKey, Notification)> {
+    let mut notifications = Vec::new();
+
+    for (series_id, series_name) in tracked_series {
+        let Ok(episode_list) = EpisodeList::new(series_id).await else {
+            continue;
+        };
+
+        if let Some(episode) = episode_list.get_previous_episode() {
+            if let Some(number) = episode.number {
+                let key = NotifiedKey {
+                    series_id,
+                    season: episode.season,
+                    episode: number,
+                    kind: NotifiedKind::Released,
+                };
+                if !already_notified.contains(&key) {
+                    notifications.push((
+                        key,
+                        Notification {
+                            series_name: series_name.clone(),
+                            kind: NotificationKind::Released,
+                        },
+                    ));
+                }
+            }
+        }
+
+        if pre_alerts_enabled {
+            if let Some((episode, release_time)) = episode_list.get_next_episode_and_time() {
+                if let Some(number) = episode.number {
+                    if release_time.is_within(PRE_ALERT_WINDOW) {
+                        let key = NotifiedKey {
+                            series_id,
+                            season: episode.season,
+                            episode: number,
+                            kind: NotifiedKind::AirsSoon,
+                        };
+                        if !already_notified.contains(&key) {
+                            notifications.push((
+                                key,
+                                Notification {
+                                    series_name: series_name.clone(),
+                                    kind: NotificationKind::AirsSoon,
+                                },
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    notifications
+}
+
+fn fire_desktop_notification(notification: &Notification) {
+    let message = match notification.kind {
+        NotificationKind::Released => {
+            format!("A new episode of {} just aired", notification.series_name)
+        }
+        NotificationKind::AirsSoon => {
+            format!("{} airs in about an hour", notification.series_name)
+        }
+    };
+
+    if let Err(err) = notify_rust::Notification::new()
+        .summary("Series Troxide")
+        .body(&message)
+        .show()
+    {
+        tracing::error!("failed to send desktop notification: {}", err);
+    }
+}
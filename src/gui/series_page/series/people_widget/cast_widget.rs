@@ -1,3 +1,5 @@
+use std::sync::mpsc;
+
 use cast_poster::{CastPoster, IndexedMessage, Message as CastMessage};
 use iced::widget::{button, column, container, row, svg, text, Space};
 use iced::{Element, Length, Task};
@@ -26,14 +28,16 @@ pub struct CastWidget {
     load_state: LoadState,
     casts: Vec<CastPoster>,
     is_expanded: bool,
+    person_page_sender: mpsc::Sender<u32>,
 }
 
 impl CastWidget {
-    pub fn new(series_id: u32) -> (Self, Task<Message>) {
+    pub fn new(series_id: u32, person_page_sender: mpsc::Sender<u32>) -> (Self, Task<Message>) {
         let cast_widget = Self {
             load_state: LoadState::Loading,
             casts: vec![],
             is_expanded: false,
+            person_page_sender,
         };
 
         let cast_command = Task::perform(caching::people::get_show_cast(series_id), |cast| {
@@ -43,6 +47,14 @@ impl CastWidget {
         (cast_widget, cast_command)
     }
 
+    pub fn subscription(&self) -> iced::Subscription<Message> {
+        iced::Subscription::batch(
+            self.casts
+                .iter()
+                .map(|poster| poster.subscription().map(Message::Cast)),
+        )
+    }
+
     pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::CastReceived(cast) => {
@@ -50,7 +62,8 @@ impl CastWidget {
                 let mut cast_posters = Vec::with_capacity(cast.len());
                 let mut posters_commands = Vec::with_capacity(cast.len());
                 for (index, person) in cast.into_iter().enumerate() {
-                    let (cast_poster, poster_command) = CastPoster::new(index, person);
+                    let (cast_poster, poster_command) =
+                        CastPoster::new(index, person, self.person_page_sender.clone());
                     cast_posters.push(cast_poster);
                     posters_commands.push(poster_command);
                 }
@@ -149,10 +162,12 @@ impl CastWidget {
 }
 
 mod cast_poster {
+    use std::sync::mpsc;
+
     use bytes::Bytes;
     use iced::{
         font::Weight,
-        widget::{button, column, container, image, row, svg, text, Column, Row, Space},
+        widget::{button, column, container, image, mouse_area, row, svg, text, Column, Row, Space},
         Element, Font, Task,
     };
 
@@ -165,7 +180,11 @@ mod cast_poster {
             },
             caching,
         },
-        gui::{assets::icons::ARROW_REPEAT, helpers, styles},
+        gui::{
+            assets::icons::ARROW_REPEAT,
+            helpers, styles,
+            troxide_widget::context_menu::{ContextMenu, MenuAction},
+        },
     };
 
     #[derive(Debug, Clone)]
@@ -173,6 +192,15 @@ mod cast_poster {
         PersonImageLoaded(Option<Bytes>),
         CharacterImageLoaded(Option<Bytes>),
         SwitchDisplayImage,
+        /// The poster was pressed; carries the id of the person to open a [`PersonTab`] for.
+        ///
+        /// [`PersonTab`]: crate::gui::tabs::person_tab::PersonTab
+        PersonSelected(u32),
+        OpenContextMenu,
+        ContextMenuMoved(iced::Point),
+        CloseContextMenu,
+        /// Handled by the series page, which routes it to a search for the actor's name
+        SearchActor,
     }
 
     enum DisplayImage {
@@ -187,10 +215,16 @@ mod cast_poster {
         character_image: Option<Bytes>,
         character_image_loading: bool,
         current_display_image: DisplayImage,
+        context_menu: ContextMenu,
+        person_page_sender: mpsc::Sender<u32>,
     }
 
     impl CastPoster {
-        pub fn new(id: usize, cast: Cast) -> (Self, Task<IndexedMessage<usize, Message>>) {
+        pub fn new(
+            id: usize,
+            cast: Cast,
+            person_page_sender: mpsc::Sender<u32>,
+        ) -> (Self, Task<IndexedMessage<usize, Message>>) {
             let image = cast.person.image.clone();
             let poster = Self {
                 index: id,
@@ -199,6 +233,8 @@ mod cast_poster {
                 character_image: None,
                 character_image_loading: false,
                 current_display_image: DisplayImage::Person,
+                context_menu: ContextMenu::new(),
+                person_page_sender,
             };
             let poster_command = Self::load_person_image(image);
             (
@@ -207,6 +243,23 @@ mod cast_poster {
             )
         }
 
+        /// Pushes this poster's person id to whoever owns the other end of
+        /// `person_page_sender`, which opens a [`PersonTab`] for it.
+        ///
+        /// [`PersonTab`]: crate::gui::tabs::person_tab::PersonTab
+        fn open_person_page(&self) {
+            self.person_page_sender
+                .send(self.cast.person.id)
+                .expect("failed to send person page info");
+        }
+
+        pub fn subscription(&self) -> iced::Subscription<IndexedMessage<usize, Message>> {
+            let index = self.index;
+            self.context_menu
+                .subscription(Message::CloseContextMenu)
+                .map(move |message| IndexedMessage::new(index, message))
+        }
+
         pub fn update(
             &mut self,
             message: IndexedMessage<usize, Message>,
@@ -237,6 +290,24 @@ mod cast_poster {
                         Task::none()
                     }
                 },
+                Message::PersonSelected(_) => {
+                    self.open_person_page();
+                    Task::none()
+                }
+                Message::OpenContextMenu => {
+                    self.context_menu.open();
+                    Task::none()
+                }
+                Message::ContextMenuMoved(position) => {
+                    self.context_menu.track_cursor(position);
+                    Task::none()
+                }
+                Message::CloseContextMenu => {
+                    self.context_menu.close();
+                    Task::none()
+                }
+                // handled by the series page, which routes it to a search for the actor's name
+                Message::SearchActor => Task::none(),
             };
             let index = self.index;
             command.map(move |message| IndexedMessage::new(index, message))
@@ -334,10 +405,27 @@ mod cast_poster {
 
             let content = content.push(cast_info);
 
-            let element: Element<'_, Message> = container(content)
+            let content = container(content)
                 .style(styles::container_styles::first_class_container_square_theme)
-                .padding(7)
+                .padding(7);
+
+            let content: Element<'_, Message> = mouse_area(content)
+                .on_press(Message::PersonSelected(self.cast.person.id))
                 .into();
+
+            let element = self.context_menu.view(
+                content,
+                Message::OpenContextMenu,
+                Message::ContextMenuMoved,
+                Message::CloseContextMenu,
+                vec![
+                    MenuAction::new(
+                        "View person page",
+                        Message::PersonSelected(self.cast.person.id),
+                    ),
+                    MenuAction::new("Search Actor", Message::SearchActor),
+                ],
+            );
             element.map(|message| IndexedMessage::new(self.index, message))
         }
 
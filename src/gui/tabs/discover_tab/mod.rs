@@ -1,13 +1,16 @@
+use std::collections::HashMap;
 use std::sync::mpsc;
 
 use crate::core::api::tv_maze::series_information::SeriesMainInformation;
+use crate::core::caching::discover;
 use crate::gui::assets::icons::BINOCULARS_FILL;
 use crate::gui::styles;
 use full_schedule::{FullSchedulePosters, Message as FullSchedulePostersMessage};
+use poster_feed::{Message as PosterFeedMessage, PosterFeed};
 use searching::Message as SearchMessage;
 
 use iced::widget::scrollable::{RelativeOffset, Viewport};
-use iced::widget::{center, column, container, scrollable, stack, Space};
+use iced::widget::{button, center, column, container, row, scrollable, stack, text, Space};
 use iced::{Element, Length, Task};
 
 use iced_aw::Spinner;
@@ -15,19 +18,61 @@ use iced_aw::Spinner;
 use super::Tab;
 
 mod full_schedule;
+mod poster_feed;
 mod searching;
 
+/// Which of `DiscoverTab`'s switchable underlays is currently shown
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum FeedKind {
+    #[default]
+    Schedule,
+    Trending,
+    ByGenre,
+    ByNetwork,
+}
+
+impl FeedKind {
+    const ALL: [Self; 4] = [
+        Self::Schedule,
+        Self::Trending,
+        Self::ByGenre,
+        Self::ByNetwork,
+    ];
+}
+
+impl std::fmt::Display for FeedKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Schedule => "Schedule",
+            Self::Trending => "Trending",
+            Self::ByGenre => "By Genre",
+            Self::ByNetwork => "By Network",
+        };
+        write!(f, "{}", label)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Message {
     Reload,
     FullSchedulePosters(FullSchedulePostersMessage),
     Search(SearchMessage),
     PageScrolled(Viewport),
+    FeedSelected(FeedKind),
+    Trending(PosterFeedMessage),
+    ByGenre(PosterFeedMessage),
+    ByNetwork(PosterFeedMessage),
 }
 
 pub struct DiscoverTab<'a> {
     search: searching::Search,
     full_schedule_series: FullSchedulePosters<'a>,
+    trending: PosterFeed<'a>,
+    by_genre: PosterFeed<'a>,
+    by_network: PosterFeed<'a>,
+    active_feed: FeedKind,
+    /// Each feed keeps its own scroll position, restored when switching back to it
+    feed_offsets: HashMap<FeedKind, RelativeOffset>,
     scrollable_offset: RelativeOffset,
 }
 
@@ -35,14 +80,32 @@ impl DiscoverTab<'_> {
     pub fn new(series_page_sender: mpsc::Sender<SeriesMainInformation>) -> (Self, Task<Message>) {
         let (full_schedule_series, full_schedule_command) =
             FullSchedulePosters::new(series_page_sender.clone());
+        let (trending, trending_command) =
+            PosterFeed::new(discover::get_trending_series(), series_page_sender.clone());
+        let (by_genre, by_genre_command) =
+            PosterFeed::new(discover::get_series_by_genre(), series_page_sender.clone());
+        let (by_network, by_network_command) = PosterFeed::new(
+            discover::get_series_by_network(),
+            series_page_sender.clone(),
+        );
 
         (
             Self {
                 search: searching::Search::new(series_page_sender),
                 full_schedule_series,
+                trending,
+                by_genre,
+                by_network,
+                active_feed: FeedKind::default(),
+                feed_offsets: HashMap::new(),
                 scrollable_offset: RelativeOffset::START,
             },
-            full_schedule_command.map(Message::FullSchedulePosters),
+            Task::batch([
+                full_schedule_command.map(Message::FullSchedulePosters),
+                trending_command.map(Message::Trending),
+                by_genre_command.map(Message::ByGenre),
+                by_network_command.map(Message::ByNetwork),
+            ]),
         )
     }
 
@@ -64,6 +127,9 @@ impl DiscoverTab<'_> {
                 }
             }),
             self.search.subscription().map(Message::Search),
+            self.trending.subscription().map(Message::Trending),
+            self.by_genre.subscription().map(Message::ByGenre),
+            self.by_network.subscription().map(Message::ByNetwork),
         ])
     }
 
@@ -78,25 +144,75 @@ impl DiscoverTab<'_> {
                 .full_schedule_series
                 .update(message)
                 .map(Message::FullSchedulePosters),
+            Message::Trending(message) => self.trending.update(message).map(Message::Trending),
+            Message::ByGenre(message) => self.by_genre.update(message).map(Message::ByGenre),
+            Message::ByNetwork(message) => self.by_network.update(message).map(Message::ByNetwork),
+            Message::FeedSelected(feed) => {
+                self.feed_offsets
+                    .insert(self.active_feed, self.scrollable_offset);
+                self.active_feed = feed;
+                self.scrollable_offset = self
+                    .feed_offsets
+                    .get(&feed)
+                    .copied()
+                    .unwrap_or(RelativeOffset::START);
+                scrollable::snap_to(Self::scrollable_id(), self.scrollable_offset)
+            }
             Message::PageScrolled(view_port) => {
                 self.scrollable_offset = view_port.relative_offset();
-                Task::none()
+                match self.active_feed {
+                    FeedKind::Schedule => Task::none(),
+                    FeedKind::Trending => self
+                        .trending
+                        .update(PosterFeedMessage::PageScrolled(view_port))
+                        .map(Message::Trending),
+                    FeedKind::ByGenre => self
+                        .by_genre
+                        .update(PosterFeedMessage::PageScrolled(view_port))
+                        .map(Message::ByGenre),
+                    FeedKind::ByNetwork => self
+                        .by_network
+                        .update(PosterFeedMessage::PageScrolled(view_port))
+                        .map(Message::ByNetwork),
+                }
             }
         }
     }
 
+    fn feed_picker(&self) -> Element<'_, Message> {
+        let mut feeds = row![].spacing(5);
+        for feed in FeedKind::ALL {
+            let label = button(text(feed.to_string()))
+                .style(if feed == self.active_feed {
+                    styles::button_styles::transparent_button_with_rounded_border_theme
+                } else {
+                    styles::button_styles::transparent_button_theme
+                })
+                .on_press(Message::FeedSelected(feed));
+            feeds = feeds.push(label);
+        }
+        feeds.into()
+    }
+
     pub fn view(&self) -> Element<'_, Message> {
-        let underlay: Element<'_, Message> =
-            if let Some(full_schedule_series) = self.full_schedule_series.view() {
-                scrollable(full_schedule_series.map(Message::FullSchedulePosters))
-                    .direction(styles::scrollable_styles::vertical_direction())
-                    .id(Self::scrollable_id())
-                    .on_scroll(Message::PageScrolled)
-                    .width(Length::Fill)
-                    .into()
-            } else {
-                center(Spinner::new()).into()
-            };
+        let underlay: Element<'_, Message> = match self.active_feed {
+            FeedKind::Schedule => {
+                if let Some(full_schedule_series) = self.full_schedule_series.view() {
+                    full_schedule_series.map(Message::FullSchedulePosters)
+                } else {
+                    center(Spinner::new()).into()
+                }
+            }
+            FeedKind::Trending => self.trending.view().map(Message::Trending),
+            FeedKind::ByGenre => self.by_genre.view().map(Message::ByGenre),
+            FeedKind::ByNetwork => self.by_network.view().map(Message::ByNetwork),
+        };
+
+        let underlay = scrollable(underlay)
+            .direction(styles::scrollable_styles::vertical_direction())
+            .id(Self::scrollable_id())
+            .on_scroll(Message::PageScrolled)
+            .width(Length::Fill);
 
         let overlay = self
             .search
@@ -107,11 +223,15 @@ impl DiscoverTab<'_> {
 
         let overlay = container(overlay).center_x(Length::Fill);
 
-        let content = stack([underlay, overlay.into()]);
+        let content = stack([underlay.into(), overlay.into()]);
 
-        column![self.search.view().0.map(Message::Search), content]
-            .spacing(2)
-            .into()
+        column![
+            self.search.view().0.map(Message::Search),
+            self.feed_picker(),
+            content
+        ]
+        .spacing(2)
+        .into()
     }
 }
 
@@ -0,0 +1,178 @@
+//! A flat grid of [`SeriesPoster`]s loaded from a single async fetch, backing `DiscoverTab`'s
+//! non-schedule feeds (Trending, By Genre, By Network). Unlike [`super::full_schedule`], these
+//! don't need per-day grouping or a local refresh pipeline, so they're just a fetch-then-display
+//! poster grid.
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::mpsc;
+
+use iced::widget::scrollable::Viewport;
+use iced::widget::{center, Space};
+use iced::{Element, Length, Task};
+use iced_aw::{Spinner, Wrap};
+
+use crate::core::api::tv_maze::series_information::SeriesMainInformation;
+use crate::gui::message::IndexedMessage;
+use crate::gui::troxide_widget::series_poster::{Message as SeriesPosterMessage, SeriesPoster};
+
+/// Rough footprint of one poster, used only to estimate which rows are currently on screen
+const POSTER_WIDTH: f32 = 110.0;
+const POSTER_HEIGHT: f32 = 180.0;
+
+/// Extra rows realized above and below the viewport so a quick scroll doesn't flash past posters
+/// whose images haven't been requested yet
+const OVERSCAN_ROWS: usize = 2;
+
+/// How many posters are realized up front, before the first scroll event reports a real
+/// viewport size
+const INITIAL_POSTER_NUMBER: usize = 40;
+
+/// How many posters beyond the realized range, on either side, keep their already-loaded image
+/// before it's dropped to bound memory on a long scroll
+const FORGET_MARGIN_ITEMS: usize = 40;
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    SeriesReceived(Vec<SeriesMainInformation>),
+    SeriesPoster(IndexedMessage<usize, SeriesPosterMessage>),
+    /// Forwarded by `DiscoverTab` whenever its shared scrollable moves while this feed is active
+    PageScrolled(Viewport),
+}
+
+enum LoadState {
+    Loading,
+    Loaded,
+}
+
+pub struct PosterFeed<'a> {
+    load_state: LoadState,
+    posters: Vec<SeriesPoster<'a>>,
+    series_page_sender: mpsc::Sender<SeriesMainInformation>,
+    last_viewport: Option<Viewport>,
+}
+
+impl<'a> PosterFeed<'a> {
+    pub fn new<F>(
+        fetch: F,
+        series_page_sender: mpsc::Sender<SeriesMainInformation>,
+    ) -> (Self, Task<Message>)
+    where
+        F: std::future::Future<Output = Vec<SeriesMainInformation>> + Send + 'static,
+    {
+        (
+            Self {
+                load_state: LoadState::Loading,
+                posters: Vec::new(),
+                series_page_sender,
+                last_viewport: None,
+            },
+            Task::perform(fetch, Message::SeriesReceived),
+        )
+    }
+
+    pub fn subscription(&self) -> iced::Subscription<Message> {
+        iced::Subscription::batch(
+            self.posters
+                .iter()
+                .map(|poster| poster.subscription().map(Message::SeriesPoster)),
+        )
+    }
+
+    /// The index range, inclusive of overscan, that should currently have a requested image
+    fn visible_index_range(&self) -> Range<usize> {
+        let total = self.posters.len();
+        if total == 0 {
+            return 0..0;
+        }
+
+        let Some(viewport) = &self.last_viewport else {
+            return 0..INITIAL_POSTER_NUMBER.min(total);
+        };
+
+        let columns = ((viewport.bounds().width / POSTER_WIDTH).floor() as usize).max(1);
+        let rows_on_screen = (viewport.bounds().height / POSTER_HEIGHT).ceil() as usize + 1;
+        let total_rows = total.div_ceil(columns);
+
+        let first_visible_row = (viewport.absolute_offset().y / POSTER_HEIGHT).floor() as usize;
+        let first_row = first_visible_row.saturating_sub(OVERSCAN_ROWS);
+        let last_row = (first_visible_row + rows_on_screen + OVERSCAN_ROWS).min(total_rows);
+
+        (first_row * columns).min(total)..(last_row * columns).min(total)
+    }
+
+    /// Requests the image for every poster within [`Self::visible_index_range`], and drops the
+    /// image of any poster that's scrolled far enough outside it
+    fn sync_visible_images(&mut self) -> Task<Message> {
+        let visible = self.visible_index_range();
+        let keep_start = visible.start.saturating_sub(FORGET_MARGIN_ITEMS);
+        let keep_end = (visible.end + FORGET_MARGIN_ITEMS).min(self.posters.len());
+        let keep = keep_start..keep_end;
+
+        let mut commands = Vec::new();
+        for index in visible {
+            commands.push(
+                self.posters[index]
+                    .request_image()
+                    .map(Message::SeriesPoster),
+            );
+        }
+        for (index, poster) in self.posters.iter_mut().enumerate() {
+            if !keep.contains(&index) {
+                poster.forget_image();
+            }
+        }
+        Task::batch(commands)
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::SeriesReceived(series) => {
+                self.load_state = LoadState::Loaded;
+
+                self.posters = series
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, series_info)| {
+                        SeriesPoster::new(
+                            index,
+                            Cow::Owned(series_info),
+                            self.series_page_sender.clone(),
+                        )
+                    })
+                    .collect();
+
+                self.sync_visible_images()
+            }
+            Message::SeriesPoster(message) => self.posters[message.index()]
+                .update(message)
+                .map(Message::SeriesPoster),
+            Message::PageScrolled(viewport) => {
+                self.last_viewport = Some(viewport);
+                self.sync_visible_images()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        match self.load_state {
+            LoadState::Loading => center(Spinner::new()).into(),
+            LoadState::Loaded => {
+                if self.posters.is_empty() {
+                    center(Space::new(Length::Shrink, Length::Shrink)).into()
+                } else {
+                    Wrap::with_elements(
+                        self.posters
+                            .iter()
+                            .map(|poster| poster.view(false).map(Message::SeriesPoster))
+                            .collect(),
+                    )
+                    .padding(5.0)
+                    .line_spacing(10.0)
+                    .spacing(10.0)
+                    .into()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! A single-entity feed for a TVmaze person: every show they've been credited in, most recently
+//! premiered first. Reached by pressing a [`CastPoster`](crate::gui::series_page::series::people_widget::cast_widget)
+//! from a series' cast list, turning the cast list from a dead end into a browsing entry point.
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::mpsc;
+
+use iced::widget::scrollable::Viewport;
+use iced::widget::{column, container, scrollable, text};
+use iced::{Element, Length, Task};
+use iced_aw::{Spinner, Wrap};
+
+use crate::core::api::tv_maze::series_information::SeriesMainInformation;
+use crate::core::caching;
+use crate::gui::message::IndexedMessage;
+use crate::gui::troxide_widget::series_poster::{Message as SeriesPosterMessage, SeriesPoster};
+
+/// Rough footprint of one poster, used only to estimate which rows are currently on screen
+const POSTER_WIDTH: f32 = 110.0;
+const POSTER_HEIGHT: f32 = 180.0;
+
+/// Extra rows realized above and below the viewport so a quick scroll doesn't flash past posters
+/// whose images haven't been requested yet
+const OVERSCAN_ROWS: usize = 2;
+
+/// How many posters are realized up front, before the first scroll event reports a real
+/// viewport size
+const INITIAL_POSTER_NUMBER: usize = 40;
+
+/// How many posters beyond the realized range, on either side, keep their already-loaded image
+/// before it's dropped to bound memory on a long scroll
+const FORGET_MARGIN_ITEMS: usize = 40;
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    CastCreditsReceived(Vec<SeriesMainInformation>),
+    SeriesPoster(IndexedMessage<usize, SeriesPosterMessage>),
+    PageScrolled(Viewport),
+}
+
+enum LoadState {
+    Loading,
+    Loaded,
+}
+
+/// Shows every show a single actor has appeared in, fetched from TVmaze's
+/// `/people/:id/castcredits?embed=show` endpoint
+pub struct PersonTab<'a> {
+    person_id: u32,
+    load_state: LoadState,
+    credits: Vec<SeriesPoster<'a>>,
+    series_page_sender: mpsc::Sender<SeriesMainInformation>,
+    last_viewport: Option<Viewport>,
+}
+
+impl<'a> PersonTab<'a> {
+    pub fn new(
+        person_id: u32,
+        series_page_sender: mpsc::Sender<SeriesMainInformation>,
+    ) -> (Self, Task<Message>) {
+        let person_tab = Self {
+            person_id,
+            load_state: LoadState::Loading,
+            credits: Vec::new(),
+            series_page_sender,
+            last_viewport: None,
+        };
+
+        let command = Task::perform(
+            caching::people::get_person_cast_credits(person_id),
+            |credits| {
+                Message::CastCreditsReceived(credits.expect("Failed to get person's cast credits"))
+            },
+        );
+
+        (person_tab, command)
+    }
+
+    pub fn person_id(&self) -> u32 {
+        self.person_id
+    }
+
+    pub fn subscription(&self) -> iced::Subscription<Message> {
+        iced::Subscription::batch(
+            self.credits
+                .iter()
+                .map(|poster| poster.subscription().map(Message::SeriesPoster)),
+        )
+    }
+
+    /// The index range, inclusive of overscan, that should currently have a requested image
+    fn visible_index_range(&self) -> Range<usize> {
+        let total = self.credits.len();
+        if total == 0 {
+            return 0..0;
+        }
+
+        let Some(viewport) = &self.last_viewport else {
+            return 0..INITIAL_POSTER_NUMBER.min(total);
+        };
+
+        let columns = ((viewport.bounds().width / POSTER_WIDTH).floor() as usize).max(1);
+        let rows_on_screen = (viewport.bounds().height / POSTER_HEIGHT).ceil() as usize + 1;
+        let total_rows = total.div_ceil(columns);
+
+        let first_visible_row = (viewport.absolute_offset().y / POSTER_HEIGHT).floor() as usize;
+        let first_row = first_visible_row.saturating_sub(OVERSCAN_ROWS);
+        let last_row = (first_visible_row + rows_on_screen + OVERSCAN_ROWS).min(total_rows);
+
+        (first_row * columns).min(total)..(last_row * columns).min(total)
+    }
+
+    /// Requests the image for every poster within [`Self::visible_index_range`], and drops the
+    /// image of any poster that's scrolled far enough outside it
+    fn sync_visible_images(&mut self) -> Task<Message> {
+        let visible = self.visible_index_range();
+        let keep_start = visible.start.saturating_sub(FORGET_MARGIN_ITEMS);
+        let keep_end = (visible.end + FORGET_MARGIN_ITEMS).min(self.credits.len());
+        let keep = keep_start..keep_end;
+
+        let mut commands = Vec::new();
+        for index in visible {
+            commands.push(
+                self.credits[index]
+                    .request_image()
+                    .map(Message::SeriesPoster),
+            );
+        }
+        for (index, poster) in self.credits.iter_mut().enumerate() {
+            if !keep.contains(&index) {
+                poster.forget_image();
+            }
+        }
+        Task::batch(commands)
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::CastCreditsReceived(mut credits) => {
+                self.load_state = LoadState::Loaded;
+
+                credits.sort_by(|a, b| b.premiered.cmp(&a.premiered));
+
+                self.credits = credits
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, series_info)| {
+                        SeriesPoster::new(
+                            index,
+                            Cow::Owned(series_info),
+                            self.series_page_sender.clone(),
+                        )
+                    })
+                    .collect();
+
+                self.sync_visible_images()
+            }
+            Message::SeriesPoster(message) => self.credits[message.index()]
+                .update(message)
+                .map(Message::SeriesPoster),
+            Message::PageScrolled(viewport) => {
+                self.last_viewport = Some(viewport);
+                self.sync_visible_images()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        match self.load_state {
+            LoadState::Loading => container(Spinner::new())
+                .center_x(Length::Fill)
+                .center_y(100)
+                .into(),
+            LoadState::Loaded => {
+                if self.credits.is_empty() {
+                    container(text("this person has no credited shows"))
+                        .center_x(Length::Fill)
+                        .padding(20)
+                        .into()
+                } else {
+                    let posters = Wrap::with_elements(
+                        self.credits
+                            .iter()
+                            .map(|poster| poster.view(false).map(Message::SeriesPoster))
+                            .collect(),
+                    )
+                    .padding(5.0)
+                    .line_spacing(10.0)
+                    .spacing(10.0);
+
+                    scrollable(column![posters].padding(10))
+                        .on_scroll(Message::PageScrolled)
+                        .width(Length::Fill)
+                        .into()
+                }
+            }
+        }
+    }
+}
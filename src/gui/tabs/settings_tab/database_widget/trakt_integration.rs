@@ -1,3 +1,16 @@
+//! `Credentials::save_credentials`/`load_from_file`/`remove_credentials` persist the connected
+//! account's token (and client secret) to a plaintext file on disk, so this widget never has to
+//! reason about where the token actually lives — only what to do when a save or load fails.
+//!
+//! That storage is not encrypted and does not use the platform keyring — the on-screen reveal in
+//! [`ClientPage`] is gated behind a deliberate second press only to guard against a casual
+//! shoulder-glance, not because the underlying file itself is any more protected.
+//!
+//! Moving to real keyring/encrypted-at-rest storage is entirely a `user_credentials` change (a
+//! zeroizing secret wrapper around `Credentials`, backed by the `keyring` crate with an
+//! AES-GCM-encrypted-file fallback) — nothing here in the widget would need to change beyond this
+//! doc comment. That module isn't part of this checkout, so it can't be done as part of this fix.
+
 use iced::widget::{button, column, horizontal_space, row, svg, text, text_input};
 use iced::{Alignment, Command, Element, Length, Renderer};
 use iced_aw::Spinner;
@@ -8,12 +21,45 @@ use crate::core::api::trakt::user_settings::{self, UserSettings};
 use crate::gui::assets::{get_static_cow_from_asset, icons::TRAKT_ICON_RED};
 use crate::gui::styles;
 
+/// The distinct ways the Trakt setup wizard can fail, so a transient API error can be shown and
+/// retried instead of panicking or silently dead-ending the wizard
+#[derive(Debug, Clone)]
+pub enum TraktAuthError {
+    DeviceCodeRequest(String),
+    TokenResponse(String),
+    UserSettingsFetch(String),
+    BrowserOpen(String),
+    CredentialsSave(String),
+}
+
+impl std::fmt::Display for TraktAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DeviceCodeRequest(err) => write!(f, "could not request a device code: {err}"),
+            Self::TokenResponse(err) => {
+                write!(f, "could not retrieve an authentication token: {err}")
+            }
+            Self::UserSettingsFetch(err) => {
+                write!(f, "could not load your Trakt account settings: {err}")
+            }
+            Self::BrowserOpen(err) => write!(f, "could not open the browser: {err}"),
+            Self::CredentialsSave(err) => {
+                write!(f, "could not save your Trakt credentials: {err}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TraktAuthError {}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     StartPage(StartPageMessage),
     ClientPage(ClientPageMessage),
     ProgramAuthenticationPage(ProgramAuthenticationPageMessage),
+    RedirectAuthenticationPage(RedirectAuthenticationPageMessage),
     ConfirmationPage(ConfirmationPageMessage),
+    ErrorPage(ErrorPageMessage),
     LoadCredentials,
     CredentialsLoaded(Credentials),
     Cancel,
@@ -29,10 +75,15 @@ impl TraktIntegration {
     }
 
     pub fn subscription(&self) -> iced::Subscription<Message> {
-        if let Some(SetupStep::ProgramAuthentication(page)) = self.setup_page.as_ref() {
-            page.subscription().map(Message::ProgramAuthenticationPage)
-        } else {
-            iced::Subscription::none()
+        match self.setup_page.as_ref() {
+            Some(SetupStep::Start(page)) => page.subscription().map(Message::StartPage),
+            Some(SetupStep::ProgramAuthentication(page)) => {
+                page.subscription().map(Message::ProgramAuthenticationPage)
+            }
+            Some(SetupStep::RedirectAuthentication(page)) => page
+                .subscription()
+                .map(Message::RedirectAuthenticationPage),
+            _ => iced::Subscription::none(),
         }
     }
 
@@ -48,8 +99,9 @@ impl TraktIntegration {
                 Message::CredentialsLoaded(credentials)
             }),
             Message::CredentialsLoaded(credentials) => {
-                self.setup_page = Some(SetupStep::Start(StartPage::new(credentials)));
-                Command::none()
+                let (start_page, command) = StartPage::new(credentials);
+                self.setup_page = Some(SetupStep::Start(start_page));
+                command.map(Message::StartPage)
             }
             Message::Cancel => {
                 self.setup_page = None;
@@ -84,6 +136,17 @@ impl TraktIntegration {
                     Command::none()
                 }
             }
+            Message::RedirectAuthenticationPage(message) => {
+                if let Some(SetupStep::RedirectAuthentication(redirect_authentication_page)) =
+                    self.setup_page.as_mut()
+                {
+                    redirect_authentication_page
+                        .update(message, &mut next_page)
+                        .map(Message::RedirectAuthenticationPage)
+                } else {
+                    Command::none()
+                }
+            }
             Message::ConfirmationPage(message) => {
                 if let Some(SetupStep::Confirmation(confirmation_page)) = self.setup_page.as_mut() {
                     confirmation_page
@@ -93,6 +156,12 @@ impl TraktIntegration {
                     Command::none()
                 }
             }
+            Message::ErrorPage(message) => {
+                if let Some(SetupStep::Error(error_page)) = self.setup_page.as_mut() {
+                    error_page.update(message, &mut next_page);
+                }
+                Command::none()
+            }
         };
 
         if let Some(next_page) = next_page {
@@ -116,9 +185,15 @@ impl TraktIntegration {
                         .view()
                         .map(Message::ProgramAuthenticationPage)
                 }
+                SetupStep::RedirectAuthentication(redirect_authentication_page) => {
+                    redirect_authentication_page
+                        .view()
+                        .map(Message::RedirectAuthenticationPage)
+                }
                 SetupStep::Confirmation(confirmation_page) => {
                     confirmation_page.view().map(Message::ConfirmationPage)
                 }
+                SetupStep::Error(error_page) => error_page.view().map(Message::ErrorPage),
                 SetupStep::None => unreachable!("SetupStep::None is only used for setup pages to go to the start not to display a view"),
             };
             column![setup_page, button("cancel").on_press(Message::Cancel),]
@@ -142,7 +217,66 @@ enum SetupStep {
     Start(StartPage),
     Client(ClientPage),
     ProgramAuthentication(ProgramAuthenticationPage),
+    RedirectAuthentication(RedirectAuthenticationPage),
     Confirmation(ConfirmationPage),
+    Error(ErrorPage),
+}
+
+/// Where a retry from [`ErrorPage`] should land, carrying whatever state is needed to recreate
+/// that page rather than starting the whole wizard over
+#[derive(Clone)]
+enum RetryStep {
+    Start,
+    Client,
+    ProgramAuthentication(CodeResponse, Client),
+    RedirectAuthentication(Client),
+}
+
+#[derive(Debug, Clone)]
+pub enum ErrorPageMessage {
+    Retry,
+    Cancel,
+}
+
+struct ErrorPage {
+    error: TraktAuthError,
+    retry_step: RetryStep,
+}
+
+impl ErrorPage {
+    fn new(error: TraktAuthError, retry_step: RetryStep) -> Self {
+        Self { error, retry_step }
+    }
+
+    fn update(&mut self, message: ErrorPageMessage, next_page: &mut Option<SetupStep>) {
+        *next_page = Some(match message {
+            ErrorPageMessage::Retry => match self.retry_step.clone() {
+                RetryStep::Start => SetupStep::None,
+                RetryStep::Client => SetupStep::Client(ClientPage::new()),
+                RetryStep::ProgramAuthentication(code, client) => {
+                    SetupStep::ProgramAuthentication(ProgramAuthenticationPage::new(code, client))
+                }
+                RetryStep::RedirectAuthentication(client) => SetupStep::RedirectAuthentication(
+                    RedirectAuthenticationPage::new(client),
+                ),
+            },
+            ErrorPageMessage::Cancel => SetupStep::None,
+        });
+    }
+
+    fn view(&self) -> Element<'_, ErrorPageMessage, Renderer> {
+        column![
+            text(format!("Error: {}", self.error)).style(styles::text_styles::red_text_theme()),
+            row![
+                button("retry").on_press(ErrorPageMessage::Retry),
+                button("cancel").on_press(ErrorPageMessage::Cancel),
+            ]
+            .spacing(10),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .into()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -150,15 +284,89 @@ pub enum StartPageMessage {
     ConnectAccount,
     RemoveAccount,
     AccountRemoved,
+    RefreshToken,
+    TokenRefreshed(Result<TokenResponse, TraktAuthError>),
+    CredentialsSaved(Result<(), TraktAuthError>),
+    BackgroundRefresh(token_refresh::Event),
+    BackgroundCredentialsSaved(Result<(), TraktAuthError>),
 }
 
 struct StartPage {
     credentials: Credentials,
+    refreshing: bool,
+    /// Kept around so a finished background refresh can be re-armed with the renewed token,
+    /// keeping the watch going instead of only ever firing once
+    refresh_sender: Option<iced::futures::channel::mpsc::Sender<token_refresh::Input>>,
 }
 
 impl StartPage {
-    pub fn new(credentials: Credentials) -> Self {
-        Self { credentials }
+    /// Builds the page, kicking off an automatic token refresh in the background if the stored
+    /// access token has already expired
+    pub fn new(credentials: Credentials) -> (Self, Command<StartPageMessage>) {
+        let refreshing = Self::token_expired(&credentials);
+        let command = if refreshing {
+            Self::refresh_command(&credentials)
+        } else {
+            Command::none()
+        };
+
+        (
+            Self {
+                credentials,
+                refreshing,
+                refresh_sender: None,
+            },
+            command,
+        )
+    }
+
+    /// Watches the connected account's token in the background, refreshing it shortly before it
+    /// expires so the user stays logged in without having to re-pair the device
+    pub fn subscription(&self) -> iced::Subscription<StartPageMessage> {
+        token_refresh::refresh_token().map(StartPageMessage::BackgroundRefresh)
+    }
+
+    /// Arms (or re-arms) the background refresh subscription with the account's current token,
+    /// if a sender from the subscription's `Event::Ready` has been received yet
+    fn arm_background_refresh(&mut self) {
+        let Some((_, token)) = self.credentials.get_data() else {
+            return;
+        };
+        let Ok(client) = user_credentials::Client::new() else {
+            return;
+        };
+        if let Some(sender) = self.refresh_sender.as_mut() {
+            let _ = sender.try_send(token_refresh::Input::RefreshToken(token, client));
+        }
+    }
+
+    fn token_expired(credentials: &Credentials) -> bool {
+        credentials
+            .get_data()
+            .map(|(_, token)| token.get_access_token().is_err())
+            .unwrap_or(false)
+    }
+
+    fn refresh_command(credentials: &Credentials) -> Command<StartPageMessage> {
+        let Some((_, token)) = credentials.get_data() else {
+            return Command::none();
+        };
+        let Ok(client) = user_credentials::Client::new() else {
+            return Command::none();
+        };
+
+        Command::perform(
+            authenication::get_refreshed_token_response(
+                token.get_refresh_token(),
+                client.client_id,
+                client.client_secret,
+            ),
+            |res| {
+                StartPageMessage::TokenRefreshed(
+                    res.map_err(|err| TraktAuthError::TokenResponse(err.to_string())),
+                )
+            },
+        )
     }
 
     pub fn update(
@@ -184,10 +392,100 @@ impl StartPage {
                 *next_page = Some(SetupStep::None);
                 Command::none()
             }
+            StartPageMessage::RefreshToken => {
+                self.refreshing = true;
+                Self::refresh_command(&self.credentials)
+            }
+            StartPageMessage::TokenRefreshed(result) => {
+                let Some((user, _)) = self.credentials.get_data() else {
+                    self.refreshing = false;
+                    return Command::none();
+                };
+
+                match result {
+                    Ok(token_response) => {
+                        let credentials = Credentials::new(token_response.into(), user.into());
+                        self.credentials = credentials.clone();
+                        Command::perform(
+                            async move { credentials.save_credentials().await },
+                            |res| {
+                                StartPageMessage::CredentialsSaved(res.map_err(|err| {
+                                    TraktAuthError::CredentialsSave(err.to_string())
+                                }))
+                            },
+                        )
+                    }
+                    Err(err) => {
+                        self.refreshing = false;
+                        *next_page = Some(SetupStep::Error(ErrorPage::new(err, RetryStep::Start)));
+                        Command::none()
+                    }
+                }
+            }
+            StartPageMessage::CredentialsSaved(result) => {
+                self.refreshing = false;
+                if let Err(err) = result {
+                    *next_page = Some(SetupStep::Error(ErrorPage::new(err, RetryStep::Start)));
+                }
+                // The startup/foreground refresh just spent the single-use refresh token; arm
+                // the background watch now rather than from `Event::Ready`, so the two never
+                // race to spend it twice.
+                self.arm_background_refresh();
+                Command::none()
+            }
+            StartPageMessage::BackgroundRefresh(event) => match event {
+                token_refresh::Event::Ready(sender) => {
+                    self.refresh_sender = Some(sender);
+                    // If a foreground refresh is already in flight (see `StartPage::new`), it
+                    // holds the only valid refresh token; arming here too would spend it twice
+                    // and strand one of the two refreshes with an already-used token. Let
+                    // `CredentialsSaved` arm it once that refresh finishes instead.
+                    if !self.refreshing {
+                        self.arm_background_refresh();
+                    }
+                    Command::none()
+                }
+                token_refresh::Event::WorkFinished(Some(token_response)) => {
+                    let Some((user, _)) = self.credentials.get_data() else {
+                        return Command::none();
+                    };
+                    let credentials = Credentials::new(token_response.into(), user.into());
+                    self.credentials = credentials.clone();
+                    Command::perform(
+                        async move { credentials.save_credentials().await },
+                        |res| {
+                            StartPageMessage::BackgroundCredentialsSaved(res.map_err(|err| {
+                                TraktAuthError::CredentialsSave(err.to_string())
+                            }))
+                        },
+                    )
+                }
+                token_refresh::Event::WorkFinished(None) => {
+                    tracing::warn!("background token refresh did not return a token");
+                    Command::none()
+                }
+            },
+            StartPageMessage::BackgroundCredentialsSaved(result) => {
+                if let Err(err) = result {
+                    tracing::error!(
+                        "failed to save the background-refreshed credentials: {}",
+                        err
+                    );
+                }
+                self.arm_background_refresh();
+                Command::none()
+            }
         }
     }
 
     pub fn view(&self) -> Element<'_, StartPageMessage, Renderer> {
+        if self.refreshing {
+            return column![Spinner::new(), text("Refreshing Trakt token")]
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .into();
+        }
+
         let content = if let Some((user, token)) = self.credentials.get_data() {
             column![
                 text("Trakt Account Status").size(18),
@@ -205,6 +503,7 @@ impl StartPage {
                 ]
                 .spacing(10),
                 button("Reconnect Trakt Account").on_press(StartPageMessage::ConnectAccount),
+                button("Refresh Token").on_press(StartPageMessage::RefreshToken),
                 button("Remove Trakt Account").on_press(StartPageMessage::RemoveAccount),
             ]
             .spacing(5)
@@ -228,14 +527,24 @@ impl StartPage {
     }
 }
 
+/// Which OAuth grant the user wants to authenticate with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginMethod {
+    /// The device-code grant: enter a short code on trakt.tv from any browser
+    DeviceCode,
+    /// The authorization-code grant: trakt.tv redirects back to a listener this app opens locally
+    BrowserRedirect,
+}
+
 #[derive(Debug, Clone)]
 pub enum ClientPageMessage {
     ClientIdChanged(String),
     ClientSecretChanged(String),
-    CodeReceived(Result<CodeResponse, String>),
+    CodeReceived(Result<CodeResponse, TraktAuthError>),
     ToggleClientIdView,
     ToggleClientSecretView,
     ToggleClientInformation,
+    LoginMethodSelected(LoginMethod),
     Submit,
 }
 
@@ -246,8 +555,12 @@ struct ClientPage {
     show_client_id: bool,
     show_client_secret: bool,
     show_client_information: bool,
+    /// Credentials are stored in a plaintext file, not encrypted at rest, so revealing a loaded
+    /// client secret on screen takes a deliberate second press rather than a single toggle —
+    /// this only guards against a casual shoulder-glance, not the file itself
+    secret_reveal_armed: bool,
+    login_method: LoginMethod,
     code_loading: bool,
-    response_error: Option<String>,
 }
 
 impl ClientPage {
@@ -259,8 +572,9 @@ impl ClientPage {
             show_client_id: false,
             show_client_secret: false,
             show_client_information: false,
+            secret_reveal_armed: false,
+            login_method: LoginMethod::DeviceCode,
             code_loading: false,
-            response_error: None,
         }
     }
 
@@ -277,17 +591,33 @@ impl ClientPage {
                 self.client_secret = text;
             }
             ClientPageMessage::Submit => {
-                self.code_loading = true;
-                return match &self.client {
-                    Ok(client) => Command::perform(
-                        authenication::get_device_code_response(client.client_id.clone()),
-                        |res| ClientPageMessage::CodeReceived(res.map_err(|err| err.to_string())),
-                    ),
-                    Err(_) => Command::perform(
-                        authenication::get_device_code_response(self.client_id.clone()),
-                        |res| ClientPageMessage::CodeReceived(res.map_err(|err| err.to_string())),
-                    ),
+                let client = if let Ok(client) = self.client.as_ref() {
+                    client.clone()
+                } else {
+                    Client {
+                        client_id: self.client_id.clone(),
+                        client_secret: self.client_secret.clone(),
+                    }
                 };
+
+                match self.login_method {
+                    LoginMethod::DeviceCode => {
+                        self.code_loading = true;
+                        return Command::perform(
+                            authenication::get_device_code_response(client.client_id),
+                            |res| {
+                                ClientPageMessage::CodeReceived(res.map_err(|err| {
+                                    TraktAuthError::DeviceCodeRequest(err.to_string())
+                                }))
+                            },
+                        );
+                    }
+                    LoginMethod::BrowserRedirect => {
+                        *next_page = Some(SetupStep::RedirectAuthentication(
+                            RedirectAuthenticationPage::new(client),
+                        ));
+                    }
+                }
             }
             ClientPageMessage::CodeReceived(code_response) => match code_response {
                 Ok(code_response) => {
@@ -304,7 +634,7 @@ impl ClientPage {
                         ProgramAuthenticationPage::new(code_response, client),
                     ));
                 }
-                Err(err) => self.response_error = Some(err),
+                Err(err) => *next_page = Some(SetupStep::Error(ErrorPage::new(err, RetryStep::Client))),
             },
             ClientPageMessage::ToggleClientIdView => {
                 self.show_client_id = !self.show_client_id;
@@ -313,18 +643,24 @@ impl ClientPage {
                 self.show_client_secret = !self.show_client_secret;
             }
             ClientPageMessage::ToggleClientInformation => {
-                self.show_client_information = !self.show_client_information
+                if self.show_client_information {
+                    self.show_client_information = false;
+                    self.secret_reveal_armed = false;
+                } else if self.secret_reveal_armed {
+                    self.show_client_information = true;
+                } else {
+                    self.secret_reveal_armed = true;
+                }
+            }
+            ClientPageMessage::LoginMethodSelected(login_method) => {
+                self.login_method = login_method;
             }
         };
         Command::none()
     }
 
     fn view(&self) -> Element<'_, ClientPageMessage, Renderer> {
-        if let Some(error_msg) = self.response_error.as_ref() {
-            text(format!("Error: {}", error_msg))
-                .style(styles::text_styles::red_text_theme())
-                .into()
-        } else if self.code_loading {
+        if self.code_loading {
             Spinner::new().into()
         } else {
             let button_content = match self.client.is_ok() {
@@ -365,9 +701,16 @@ impl ClientPage {
                             .into(),
                             "hide",
                         )
+                    } else if self.secret_reveal_armed {
+                        (
+                            text("press \"show\" again to reveal your client secret in plaintext")
+                                .style(styles::text_styles::red_text_theme())
+                                .into(),
+                            "show",
+                        )
                     } else {
                         (
-                            text("client information has been loaded from environment variables")
+                            text("client information has been loaded from your stored credentials")
                                 .into(),
                             "show",
                         )
@@ -403,13 +746,33 @@ impl ClientPage {
                 .align_items(Alignment::Center),
             };
 
-            column![content, submit_button]
+            column![content, Self::login_method_selector(self.login_method), submit_button]
                 .align_items(Alignment::Center)
                 .spacing(5)
                 .into()
         }
     }
 
+    fn login_method_selector(selected: LoginMethod) -> Element<'static, ClientPageMessage, Renderer> {
+        let method_button = |label: &'static str, method: LoginMethod| {
+            let mut method_button = button(label);
+            if selected != method {
+                method_button =
+                    method_button.on_press(ClientPageMessage::LoginMethodSelected(method));
+            }
+            method_button
+        };
+
+        row![
+            text("log in with:"),
+            method_button("device code", LoginMethod::DeviceCode),
+            method_button("browser redirect", LoginMethod::BrowserRedirect),
+        ]
+        .spacing(10)
+        .align_items(Alignment::Center)
+        .into()
+    }
+
     fn client_field_input<'a, F>(
         placeholder: &'a str,
         text_input_value: &'a str,
@@ -440,9 +803,10 @@ impl ClientPage {
 #[derive(Debug, Clone)]
 pub enum ProgramAuthenticationPageMessage {
     AuthenticationEvent(code_authentication::Event),
-    UserSettingsLoaded(UserSettings),
+    UserSettingsLoaded(Result<UserSettings, TraktAuthError>),
     CopyCodeToClipboard,
     OpenVerificationUrl,
+    CancelAuthentication,
 }
 
 struct ProgramAuthenticationPage {
@@ -451,6 +815,9 @@ struct ProgramAuthenticationPage {
     count_down: u32,
     token_response: Option<TokenResponse>,
     token_response_loaded: bool,
+    /// Kept around so [`ProgramAuthenticationPageMessage::CancelAuthentication`] can tell the
+    /// subscription's polling task to stop instead of leaking it when this page is torn down
+    work_sender: Option<iced::futures::channel::mpsc::Sender<code_authentication::Input>>,
 }
 impl ProgramAuthenticationPage {
     fn new(code_response: CodeResponse, client: Client) -> Self {
@@ -461,6 +828,7 @@ impl ProgramAuthenticationPage {
             count_down,
             token_response: None,
             token_response_loaded: false,
+            work_sender: None,
         }
     }
 
@@ -478,47 +846,107 @@ impl ProgramAuthenticationPage {
             ProgramAuthenticationPageMessage::AuthenticationEvent(event) => match event {
                 code_authentication::Event::Ready(mut work_sender) => {
                     if !self.token_response_loaded {
-                        work_sender
-                            .try_send(code_authentication::Input::AuthenticateCode(
+                        if let Err(err) = work_sender.try_send(
+                            code_authentication::Input::AuthenticateCode(
                                 self.code.clone(),
                                 self.client.clone(),
-                            ))
-                            .expect("failed to send code to the authenticator");
+                            ),
+                        ) {
+                            *next_page = Some(SetupStep::Error(ErrorPage::new(
+                                TraktAuthError::DeviceCodeRequest(err.to_string()),
+                                RetryStep::ProgramAuthentication(
+                                    self.code.clone(),
+                                    self.client.clone(),
+                                ),
+                            )));
+                        }
+                        self.work_sender = Some(work_sender);
                     }
                 }
                 code_authentication::Event::WorkFinished(token) => {
                     self.token_response_loaded = true;
-                    if let Some(token) = token {
-                        let access_token = token.access_token.clone();
-                        let client_id = self.client.client_id.clone();
-                        self.token_response = Some(token);
-                        return Command::perform(
-                            user_settings::get_user_settings(client_id.leak(), access_token),
-                            |res| {
-                                ProgramAuthenticationPageMessage::UserSettingsLoaded(
-                                    res.expect("failed to load user settings"),
-                                )
-                            },
-                        );
+                    match token {
+                        Some(token) => {
+                            let access_token = token.access_token.clone();
+                            let client_id = self.client.client_id.clone();
+                            self.token_response = Some(token);
+                            return Command::perform(
+                                user_settings::get_user_settings(client_id.leak(), access_token),
+                                |res| {
+                                    ProgramAuthenticationPageMessage::UserSettingsLoaded(
+                                        res.map_err(|err| {
+                                            TraktAuthError::UserSettingsFetch(err.to_string())
+                                        }),
+                                    )
+                                },
+                            );
+                        }
+                        None => {
+                            *next_page = Some(SetupStep::Error(ErrorPage::new(
+                                TraktAuthError::TokenResponse(
+                                    "the authentication attempt did not return a token".into(),
+                                ),
+                                RetryStep::ProgramAuthentication(
+                                    self.code.clone(),
+                                    self.client.clone(),
+                                ),
+                            )));
+                        }
                     }
                 }
-                code_authentication::Event::Progressing => self.count_down -= self.code.interval,
+                code_authentication::Event::Progressing => {
+                    self.count_down = self.count_down.saturating_sub(self.code.interval)
+                }
+                code_authentication::Event::CodeRenewed(code_response) => {
+                    self.count_down = code_response.expires_in;
+                    self.code = code_response;
+                }
+                code_authentication::Event::Cancelled => {
+                    *next_page = Some(SetupStep::Client(ClientPage::new()));
+                }
+                code_authentication::Event::Error(err) => {
+                    *next_page = Some(SetupStep::Error(ErrorPage::new(
+                        TraktAuthError::TokenResponse(err.to_string()),
+                        RetryStep::ProgramAuthentication(self.code.clone(), self.client.clone()),
+                    )));
+                }
             },
             ProgramAuthenticationPageMessage::CopyCodeToClipboard => {
                 return iced::clipboard::write(self.code.user_code.clone())
             }
             ProgramAuthenticationPageMessage::OpenVerificationUrl => {
-                webbrowser::open(&self.code.verification_url).unwrap_or_else(|err| {
-                    tracing::error!("failed to open trakt verification url: {}", err)
-                });
+                if let Err(err) = webbrowser::open(&self.code.verification_url) {
+                    *next_page = Some(SetupStep::Error(ErrorPage::new(
+                        TraktAuthError::BrowserOpen(err.to_string()),
+                        RetryStep::ProgramAuthentication(self.code.clone(), self.client.clone()),
+                    )));
+                }
             }
             ProgramAuthenticationPageMessage::UserSettingsLoaded(user_settings) => {
-                *next_page = Some(SetupStep::Confirmation(ConfirmationPage::new(
-                    self.token_response
-                        .clone()
-                        .expect("there should be token response at this point!"),
-                    user_settings,
-                )))
+                match user_settings {
+                    Ok(user_settings) => {
+                        *next_page = Some(SetupStep::Confirmation(ConfirmationPage::new(
+                            self.token_response
+                                .clone()
+                                .expect("token response is set before user settings are requested"),
+                            user_settings,
+                        )))
+                    }
+                    Err(err) => {
+                        *next_page = Some(SetupStep::Error(ErrorPage::new(
+                            err,
+                            RetryStep::ProgramAuthentication(
+                                self.code.clone(),
+                                self.client.clone(),
+                            ),
+                        )));
+                    }
+                }
+            }
+            ProgramAuthenticationPageMessage::CancelAuthentication => {
+                if let Some(work_sender) = self.work_sender.as_mut() {
+                    let _ = work_sender.try_send(code_authentication::Input::Cancel);
+                }
             }
         }
         Command::none()
@@ -557,7 +985,10 @@ impl ProgramAuthenticationPage {
                         .on_press(ProgramAuthenticationPageMessage::OpenVerificationUrl)
                 ]
                 .spacing(10),
-                text(format!("{} seconds to expiration", self.count_down))
+                text(format!("{} seconds to expiration", self.count_down)),
+                button("cancel")
+                    .style(styles::button_styles::transparent_button_with_rounded_border_theme())
+                    .on_press(ProgramAuthenticationPageMessage::CancelAuthentication)
             ]
             .spacing(5)
             .align_items(Alignment::Center)
@@ -566,10 +997,128 @@ impl ProgramAuthenticationPage {
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum RedirectAuthenticationPageMessage {
+    AuthenticationEvent(redirect_authentication::Event),
+    UserSettingsLoaded(Result<UserSettings, TraktAuthError>),
+    OpenAuthorizationUrl,
+}
+
+struct RedirectAuthenticationPage {
+    client: Client,
+    authorization_url: String,
+    token_response: Option<TokenResponse>,
+    token_response_loaded: bool,
+}
+
+impl RedirectAuthenticationPage {
+    fn new(client: Client) -> Self {
+        let authorization_url =
+            authenication::build_authorization_url(&client.client_id, redirect_authentication::REDIRECT_URI);
+        Self {
+            client,
+            authorization_url,
+            token_response: None,
+            token_response_loaded: false,
+        }
+    }
+
+    fn subscription(&self) -> iced::Subscription<RedirectAuthenticationPageMessage> {
+        redirect_authentication::listen_for_redirect(self.client.clone())
+            .map(RedirectAuthenticationPageMessage::AuthenticationEvent)
+    }
+
+    fn update(
+        &mut self,
+        message: RedirectAuthenticationPageMessage,
+        next_page: &mut Option<SetupStep>,
+    ) -> Command<RedirectAuthenticationPageMessage> {
+        match message {
+            RedirectAuthenticationPageMessage::AuthenticationEvent(event) => match event {
+                redirect_authentication::Event::WorkFinished(token) => {
+                    self.token_response_loaded = true;
+                    match token {
+                        Some(token) => {
+                            let access_token = token.access_token.clone();
+                            let client_id = self.client.client_id.clone();
+                            self.token_response = Some(token);
+                            return Command::perform(
+                                user_settings::get_user_settings(client_id.leak(), access_token),
+                                |res| {
+                                    RedirectAuthenticationPageMessage::UserSettingsLoaded(
+                                        res.map_err(|err| {
+                                            TraktAuthError::UserSettingsFetch(err.to_string())
+                                        }),
+                                    )
+                                },
+                            );
+                        }
+                        None => {
+                            *next_page = Some(SetupStep::Error(ErrorPage::new(
+                                TraktAuthError::TokenResponse(
+                                    "the browser authorization did not return a token".into(),
+                                ),
+                                RetryStep::RedirectAuthentication(self.client.clone()),
+                            )));
+                        }
+                    }
+                }
+            },
+            RedirectAuthenticationPageMessage::OpenAuthorizationUrl => {
+                if let Err(err) = webbrowser::open(&self.authorization_url) {
+                    *next_page = Some(SetupStep::Error(ErrorPage::new(
+                        TraktAuthError::BrowserOpen(err.to_string()),
+                        RetryStep::RedirectAuthentication(self.client.clone()),
+                    )));
+                }
+            }
+            RedirectAuthenticationPageMessage::UserSettingsLoaded(user_settings) => {
+                match user_settings {
+                    Ok(user_settings) => {
+                        *next_page = Some(SetupStep::Confirmation(ConfirmationPage::new(
+                            self.token_response
+                                .clone()
+                                .expect("token response is set before user settings are requested"),
+                            user_settings,
+                        )))
+                    }
+                    Err(err) => {
+                        *next_page = Some(SetupStep::Error(ErrorPage::new(
+                            err,
+                            RetryStep::RedirectAuthentication(self.client.clone()),
+                        )));
+                    }
+                }
+            }
+        }
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, RedirectAuthenticationPageMessage, Renderer> {
+        if self.token_response_loaded {
+            column![Spinner::new(), text("Loading account settings"),]
+                .spacing(5)
+                .align_items(Alignment::Center)
+                .into()
+        } else {
+            column![
+                text("waiting for authorization in your browser"),
+                button(text(&self.authorization_url))
+                    .style(styles::button_styles::transparent_button_with_rounded_border_theme())
+                    .on_press(RedirectAuthenticationPageMessage::OpenAuthorizationUrl),
+                Spinner::new(),
+            ]
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .into()
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfirmationPageMessage {
     SaveCredentials,
-    CredentialsSaved,
+    CredentialsSaved(Result<(), TraktAuthError>),
 }
 
 struct ConfirmationPage {
@@ -598,14 +1147,16 @@ impl ConfirmationPage {
                 );
 
                 Command::perform(async move { credentials.save_credentials().await }, |res| {
-                    if let Err(err) = res {
-                        tracing::error!("failed to save credentials file: {}", err)
-                    };
-                    ConfirmationPageMessage::CredentialsSaved
+                    ConfirmationPageMessage::CredentialsSaved(
+                        res.map_err(|err| TraktAuthError::CredentialsSave(err.to_string())),
+                    )
                 })
             }
-            ConfirmationPageMessage::CredentialsSaved => {
-                *next_page = Some(SetupStep::None);
+            ConfirmationPageMessage::CredentialsSaved(result) => {
+                *next_page = Some(match result {
+                    Ok(()) => SetupStep::None,
+                    Err(err) => SetupStep::Error(ErrorPage::new(err, RetryStep::Start)),
+                });
                 Command::none()
             }
         }
@@ -630,23 +1181,55 @@ impl ConfirmationPage {
 }
 
 mod code_authentication {
-    use crate::core::api::trakt::authenication::{get_token_response, CodeResponse, TokenResponse};
+    use crate::core::api::trakt::authenication::{self, CodeResponse, TokenPollStatus, TokenResponse};
     use crate::core::api::trakt::user_credentials::Client;
 
     use iced::futures::channel::mpsc;
     use iced::futures::sink::SinkExt;
     use iced::subscription::{self, Subscription};
 
+    /// The backoff RFC 8628 asks clients to apply to the poll interval on a `slow_down` response
+    const SLOW_DOWN_STEP: u32 = 5;
+
     #[derive(Debug, Clone)]
     pub enum Event {
         Ready(mpsc::Sender<Input>),
         WorkFinished(Option<TokenResponse>),
         Progressing,
+        /// The device code's validity window closed before the user authorized it; a fresh code
+        /// was requested automatically and polling is continuing with it
+        CodeRenewed(CodeResponse),
+        /// Polling was stopped by an `Input::Cancel` before it produced a token
+        Cancelled,
+        /// Polling reached a terminal failure and gave up on this code
+        Error(AuthError),
+    }
+
+    #[derive(Debug, Clone)]
+    pub enum AuthError {
+        AccessDenied,
+        Network(String),
+    }
+
+    impl std::fmt::Display for AuthError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Self::AccessDenied => write!(f, "authorization was denied"),
+                Self::Network(err) => {
+                    write!(f, "a network error occurred while polling for the token: {err}")
+                }
+            }
+        }
     }
 
+    impl std::error::Error for AuthError {}
+
     #[derive(Debug, Clone)]
     pub enum Input {
         AuthenticateCode(CodeResponse, Client),
+        /// Stops an in-flight poll, aborting its `tokio::spawn`ed task rather than leaving it to
+        /// run detached in the background
+        Cancel,
     }
 
     enum State {
@@ -654,11 +1237,45 @@ mod code_authentication {
         Ready(mpsc::Receiver<Input>),
     }
 
+    /// What one round of device-code polling produced
+    enum PollOutcome {
+        Token(TokenResponse),
+        CodeExpired,
+        Error(AuthError),
+    }
+
+    /// Exponential backoff for the transient transport errors that can occur between otherwise
+    /// valid polls; resets to `initial` as soon as a poll reaches the token endpoint successfully
+    struct Backoff {
+        initial: std::time::Duration,
+        max: std::time::Duration,
+        current: std::time::Duration,
+    }
+
+    impl Backoff {
+        fn new(initial: std::time::Duration, max: std::time::Duration) -> Self {
+            Self {
+                initial,
+                max,
+                current: initial,
+            }
+        }
+
+        fn reset(&mut self) {
+            self.current = self.initial;
+        }
+
+        async fn wait(&mut self) {
+            tokio::time::sleep(self.current).await;
+            self.current = (self.current * 2).min(self.max);
+        }
+    }
+
     pub fn authenticate_code() -> Subscription<Event> {
         subscription::channel("code-authenticator", 100, |mut output| async move {
             let mut state = State::Starting;
 
-            loop {
+            'outer: loop {
                 match &mut state {
                     State::Starting => {
                         let (sender, receiver) = mpsc::channel(100);
@@ -675,41 +1292,362 @@ mod code_authentication {
 
                         let input = receiver.select_next_some().await;
 
-                        #[allow(irrefutable_let_patterns)]
-                        if let Input::AuthenticateCode(code_response, client) = input {
+                        let (mut code_response, client) = match input {
+                            Input::AuthenticateCode(code_response, client) => {
+                                (code_response, client)
+                            }
+                            // nothing to cancel until a poll has actually started
+                            Input::Cancel => continue,
+                        };
+
+                        notify_code_ready(&code_response);
+
+                        let token_response = 'auth: loop {
                             let (countdown_sender, mut countdown_receiver) =
                                 tokio::sync::mpsc::channel(code_response.expires_in as usize);
 
+                            let poll_code = code_response.clone();
+                            let poll_client = client.clone();
                             let handle = tokio::spawn(async move {
-                                get_token_response(
-                                    code_response.device_code,
-                                    code_response.interval,
-                                    code_response.expires_in,
-                                    client.client_id,
-                                    client.client_secret,
-                                    countdown_sender,
-                                )
-                                .await
+                                poll_for_token(poll_code, poll_client, countdown_sender).await
                             });
 
-                            while (countdown_receiver.recv().await).is_some() {
-                                output
-                                    .send(Event::Progressing)
+                            loop {
+                                tokio::select! {
+                                    tick = countdown_receiver.recv() => {
+                                        match tick {
+                                            Some(()) => {
+                                                output
+                                                    .send(Event::Progressing)
+                                                    .await
+                                                    .expect("failed to send the progress");
+                                            }
+                                            None => break,
+                                        }
+                                    }
+                                    input = receiver.select_next_some() => {
+                                        if let Input::Cancel = input {
+                                            handle.abort();
+                                            output
+                                                .send(Event::Cancelled)
+                                                .await
+                                                .expect("failed to send cancellation");
+                                            state = State::Starting;
+                                            continue 'outer;
+                                        }
+                                    }
+                                }
+                            }
+
+                            match handle.await.expect("failed to await polling handle") {
+                                PollOutcome::Token(token) => break 'auth Ok(Some(token)),
+                                PollOutcome::Error(err) => break 'auth Err(err),
+                                PollOutcome::CodeExpired => {
+                                    match authenication::get_device_code_response(
+                                        client.client_id.clone(),
+                                    )
                                     .await
-                                    .expect("failed to send the progress");
+                                    {
+                                        Ok(renewed) => {
+                                            code_response = renewed.clone();
+                                            output
+                                                .send(Event::CodeRenewed(renewed))
+                                                .await
+                                                .expect("failed to send renewed code");
+                                        }
+                                        Err(err) => {
+                                            break 'auth Err(AuthError::Network(err.to_string()))
+                                        }
+                                    }
+                                }
                             }
+                        };
 
-                            let token_response = handle
-                                .await
-                                .expect("failed to await progress handle")
-                                .expect("failed to get token response");
+                        if let Ok(Some(_)) = &token_response {
+                            notify_signed_in();
+                        }
 
-                            output
-                                .send(Event::WorkFinished(token_response))
+                        match token_response {
+                            Ok(token) => output
+                                .send(Event::WorkFinished(token))
                                 .await
-                                .expect("failed to send work completion");
-                            state = State::Starting;
+                                .expect("failed to send work completion"),
+                            Err(err) => output
+                                .send(Event::Error(err))
+                                .await
+                                .expect("failed to send auth error"),
                         }
+                        state = State::Starting;
+                    }
+                }
+            }
+        })
+    }
+
+    /// A transport error this many times in a row, with no successful contact with the token
+    /// endpoint in between, is treated as a terminal failure rather than retried again
+    const MAX_CONSECUTIVE_TRANSPORT_FAILURES: u32 = 5;
+
+    /// Polls the token endpoint at `code_response.interval` (permanently backing off by
+    /// [`SLOW_DOWN_STEP`] on `slow_down`, per RFC 8628) until the device is authorized, denied,
+    /// or its code expires, ticking `countdown_sender` once per attempt so the subscription can
+    /// report progress back to the UI. Transient transport errors are retried with exponential
+    /// backoff rather than failing the poll outright.
+    async fn poll_for_token(
+        code_response: CodeResponse,
+        client: Client,
+        countdown_sender: tokio::sync::mpsc::Sender<()>,
+    ) -> PollOutcome {
+        let mut interval = code_response.interval;
+        let mut remaining = code_response.expires_in;
+        let mut backoff = Backoff::new(
+            std::time::Duration::from_secs(1),
+            std::time::Duration::from_secs(30),
+        );
+        let mut consecutive_transport_failures = 0;
+
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(interval as u64)).await;
+            let _ = countdown_sender.send(()).await;
+            remaining = remaining.saturating_sub(interval);
+
+            if remaining == 0 {
+                return PollOutcome::CodeExpired;
+            }
+
+            match authenication::poll_device_token(
+                code_response.device_code.clone(),
+                client.client_id.clone(),
+                client.client_secret.clone(),
+            )
+            .await
+            {
+                Ok(TokenPollStatus::Success(token)) => return PollOutcome::Token(token),
+                Ok(TokenPollStatus::AuthorizationPending) => {
+                    backoff.reset();
+                    consecutive_transport_failures = 0;
+                }
+                Ok(TokenPollStatus::SlowDown) => {
+                    backoff.reset();
+                    consecutive_transport_failures = 0;
+                    interval += SLOW_DOWN_STEP;
+                }
+                Ok(TokenPollStatus::ExpiredToken) => return PollOutcome::CodeExpired,
+                Ok(TokenPollStatus::AccessDenied) => {
+                    return PollOutcome::Error(AuthError::AccessDenied)
+                }
+                Err(err) => {
+                    consecutive_transport_failures += 1;
+                    if consecutive_transport_failures >= MAX_CONSECUTIVE_TRANSPORT_FAILURES {
+                        return PollOutcome::Error(AuthError::Network(err.to_string()));
+                    }
+                    backoff.wait().await;
+                }
+            }
+        }
+    }
+
+    /// Tells the user a device code is ready to be entered, since pairing involves switching away
+    /// to a browser and they may no longer have the app window in view
+    fn notify_code_ready(code_response: &CodeResponse) {
+        if let Err(err) = notify_rust::Notification::new()
+            .summary("Series Troxide")
+            .body(&format!(
+                "enter code {} at {} to finish signing in to Trakt",
+                code_response.user_code, code_response.verification_url
+            ))
+            .show()
+        {
+            tracing::warn!("failed to send device-code pairing notification: {}", err);
+        }
+    }
+
+    fn notify_signed_in() {
+        if let Err(err) = notify_rust::Notification::new()
+            .summary("Series Troxide")
+            .body("successfully signed in to Trakt")
+            .show()
+        {
+            tracing::warn!("failed to send sign-in notification: {}", err);
+        }
+    }
+}
+
+mod redirect_authentication {
+    use super::Client;
+    use crate::core::api::trakt::authenication::{self, TokenResponse};
+
+    use iced::subscription::{self, Subscription};
+    use iced::futures::sink::SinkExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Loopback address trakt.tv redirects the user's browser back to once they authorize the app
+    pub const REDIRECT_URI: &str = "http://127.0.0.1:53121/callback";
+
+    #[derive(Debug, Clone)]
+    pub enum Event {
+        WorkFinished(Option<TokenResponse>),
+    }
+
+    /// Starts a one-shot localhost listener for the OAuth redirect and exchanges the authorization
+    /// code it receives for a token
+    pub fn listen_for_redirect(client: Client) -> Subscription<Event> {
+        subscription::channel("redirect-authenticator", 1, |mut output| async move {
+            let token = receive_authorization_code(client).await;
+            output
+                .send(Event::WorkFinished(token))
+                .await
+                .expect("failed to send work completion");
+
+            // the listener only ever handles a single redirect; park here so the subscription
+            // doesn't restart and bind the port again
+            std::future::pending::<()>().await;
+            unreachable!("a parked subscription future never resolves")
+        })
+    }
+
+    async fn receive_authorization_code(client: Client) -> Option<TokenResponse> {
+        let listener = TcpListener::bind(("127.0.0.1", 53121)).await.ok()?;
+        let (mut stream, _) = listener.accept().await.ok()?;
+
+        let mut buffer = [0u8; 1024];
+        let read = stream.read(&mut buffer).await.ok()?;
+        let request = String::from_utf8_lossy(&buffer[..read]);
+        let code = parse_authorization_code(&request)?;
+
+        let body = "Trakt authorization complete, you can return to Series Troxide.";
+        let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+        let _ = stream.write_all(response.as_bytes()).await;
+
+        authenication::exchange_authorization_code(
+            code,
+            client.client_id,
+            client.client_secret,
+            REDIRECT_URI.to_owned(),
+        )
+        .await
+        .ok()
+    }
+
+    /// Pulls the `code` query parameter out of the request line of a raw HTTP request
+    fn parse_authorization_code(request: &str) -> Option<String> {
+        let request_line = request.lines().next()?;
+        let path = request_line.split_whitespace().nth(1)?;
+        let query = path.split_once('?')?.1;
+        query.split('&').find_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            (key == "code").then(|| value.to_owned())
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::parse_authorization_code;
+
+        #[test]
+        fn parse_authorization_code_reads_the_code_parameter() {
+            let request = "GET /callback?code=abc123 HTTP/1.1\r\nHost: 127.0.0.1:53121\r\n\r\n";
+            assert_eq!(
+                parse_authorization_code(request).as_deref(),
+                Some("abc123")
+            );
+        }
+
+        #[test]
+        fn parse_authorization_code_finds_code_among_other_parameters() {
+            let request = "GET /callback?state=xyz&code=abc123&foo=bar HTTP/1.1\r\n\r\n";
+            assert_eq!(
+                parse_authorization_code(request).as_deref(),
+                Some("abc123")
+            );
+        }
+
+        #[test]
+        fn parse_authorization_code_is_none_without_a_code_parameter() {
+            let request = "GET /callback?state=xyz HTTP/1.1\r\n\r\n";
+            assert_eq!(parse_authorization_code(request), None);
+        }
+
+        #[test]
+        fn parse_authorization_code_is_none_without_a_query_string() {
+            let request = "GET /callback HTTP/1.1\r\n\r\n";
+            assert_eq!(parse_authorization_code(request), None);
+        }
+    }
+}
+
+mod token_refresh {
+    use crate::core::api::trakt::authenication::{self, TokenResponse};
+    use crate::core::api::trakt::user_credentials::{Client, Token};
+
+    use iced::futures::channel::mpsc;
+    use iced::futures::sink::SinkExt;
+    use iced::subscription::{self, Subscription};
+
+    /// How long before a token's expiration a refresh is attempted, so the access token already
+    /// in use never actually goes stale under normal use
+    const REFRESH_LEAD_SECONDS: u32 = 300;
+
+    #[derive(Debug, Clone)]
+    pub enum Event {
+        Ready(mpsc::Sender<Input>),
+        WorkFinished(Option<TokenResponse>),
+    }
+
+    #[derive(Debug, Clone)]
+    pub enum Input {
+        /// Arms the watch for this token, self-scheduling a wake shortly before it expires
+        RefreshToken(Token, Client),
+    }
+
+    enum State {
+        Starting,
+        Ready(mpsc::Receiver<Input>),
+    }
+
+    /// Watches a connected account's token in the background, refreshing it shortly before it
+    /// expires so a user who leaves the app open is never forced back through device pairing.
+    /// Mirrors [`super::code_authentication::authenticate_code`]'s channel/state-machine shape,
+    /// but re-arms itself after every refresh instead of running once.
+    pub fn refresh_token() -> Subscription<Event> {
+        subscription::channel("token-refresher", 100, |mut output| async move {
+            let mut state = State::Starting;
+
+            loop {
+                match &mut state {
+                    State::Starting => {
+                        let (sender, receiver) = mpsc::channel(100);
+
+                        output
+                            .send(Event::Ready(sender))
+                            .await
+                            .expect("failed to send input sender");
+
+                        state = State::Ready(receiver);
+                    }
+                    State::Ready(receiver) => {
+                        use iced::futures::StreamExt;
+
+                        let Input::RefreshToken(token, client) =
+                            receiver.select_next_some().await;
+
+                        let wait = token.get_expires_in().saturating_sub(REFRESH_LEAD_SECONDS);
+                        tokio::time::sleep(std::time::Duration::from_secs(wait as u64)).await;
+
+                        let refreshed = authenication::get_refreshed_token_response(
+                            token.get_refresh_token(),
+                            client.client_id,
+                            client.client_secret,
+                        )
+                        .await
+                        .ok();
+
+                        output
+                            .send(Event::WorkFinished(refreshed))
+                            .await
+                            .expect("failed to send work completion");
+                        state = State::Starting;
                     }
                 }
             }
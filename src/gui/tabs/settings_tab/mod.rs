@@ -0,0 +1,184 @@
+//! The Settings tab's data-management section: lets the user export the aggregated upcoming
+//! episode schedule to a calendar/RSS feed, and move the tracked-show library itself in or out
+//! via the native JSON or OPML formats, without leaving the app.
+//!
+//! Paths are typed in rather than picked from a native file dialog - this crate doesn't
+//! currently depend on one.
+
+use iced::widget::{button, column, row, text, text_input};
+use iced::{Command, Element, Length};
+
+use crate::core::feed_export::{self, FeedExportError};
+use crate::core::import_export::{self, ImportExportError};
+use crate::gui::assets::icons::GEAR_FILL;
+use crate::gui::troxide_widget;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SchedulePathChanged(String),
+    ExportIcsPressed,
+    ExportRssPressed,
+    ScheduleExportFinished(Result<&'static str, String>),
+    LibraryPathChanged(String),
+    ExportJsonPressed,
+    ImportJsonPressed,
+    ExportOpmlPressed,
+    ImportOpmlPressed,
+    LibraryTransferFinished(Result<&'static str, String>),
+}
+
+#[derive(Default)]
+pub struct SettingsTab {
+    schedule_export_path: String,
+    schedule_export_status: Option<Result<&'static str, String>>,
+    library_path: String,
+    library_transfer_status: Option<Result<&'static str, String>>,
+}
+
+impl SettingsTab {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::SchedulePathChanged(path) => {
+                self.schedule_export_path = path;
+                Command::none()
+            }
+            Message::ExportIcsPressed => {
+                let path = self.schedule_export_path.clone();
+                Command::perform(
+                    async move { export_schedule(feed_export::export_ics(path).await) },
+                    Message::ScheduleExportFinished,
+                )
+            }
+            Message::ExportRssPressed => {
+                let path = self.schedule_export_path.clone();
+                Command::perform(
+                    async move { export_schedule(feed_export::export_rss(path).await) },
+                    Message::ScheduleExportFinished,
+                )
+            }
+            Message::ScheduleExportFinished(result) => {
+                self.schedule_export_status = Some(result);
+                Command::none()
+            }
+            Message::LibraryPathChanged(path) => {
+                self.library_path = path;
+                Command::none()
+            }
+            Message::ExportJsonPressed => {
+                let path = self.library_path.clone();
+                Command::perform(
+                    async move { transfer_library(import_export::export_json(path).await, "exported") },
+                    Message::LibraryTransferFinished,
+                )
+            }
+            Message::ImportJsonPressed => {
+                let path = self.library_path.clone();
+                Command::perform(
+                    async move { transfer_library(import_export::import_json(path).await, "imported") },
+                    Message::LibraryTransferFinished,
+                )
+            }
+            Message::ExportOpmlPressed => {
+                let path = self.library_path.clone();
+                Command::perform(
+                    async move { transfer_library(import_export::export_opml(path).await, "exported") },
+                    Message::LibraryTransferFinished,
+                )
+            }
+            Message::ImportOpmlPressed => {
+                let path = self.library_path.clone();
+                Command::perform(
+                    async move { transfer_library(import_export::import_opml(path).await, "imported") },
+                    Message::LibraryTransferFinished,
+                )
+            }
+            Message::LibraryTransferFinished(result) => {
+                self.library_transfer_status = Some(result);
+                Command::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let path_input = text_input("Export path, e.g. schedule.ics", &self.schedule_export_path)
+            .on_input(Message::SchedulePathChanged)
+            .width(Length::Fixed(300.0));
+
+        let controls = row![
+            path_input,
+            button("Export as .ics").on_press(Message::ExportIcsPressed),
+            button("Export as .rss").on_press(Message::ExportRssPressed),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+        let mut content = column![text("Schedule export").size(16), controls].spacing(5);
+
+        if let Some(status) = &self.schedule_export_status {
+            let status_text = match status {
+                Ok(message) => text(*message),
+                Err(err) => text(err.clone()),
+            };
+            content = content.push(status_text);
+        }
+
+        let library_path_input = text_input("Library path, e.g. library.json", &self.library_path)
+            .on_input(Message::LibraryPathChanged)
+            .width(Length::Fixed(300.0));
+
+        let library_controls = row![
+            library_path_input,
+            button("Export JSON").on_press(Message::ExportJsonPressed),
+            button("Import JSON").on_press(Message::ImportJsonPressed),
+            button("Export OPML").on_press(Message::ExportOpmlPressed),
+            button("Import OPML").on_press(Message::ImportOpmlPressed),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+        content = content.push(text("Library import/export").size(16));
+        content = content.push(library_controls);
+
+        if let Some(status) = &self.library_transfer_status {
+            let status_text = match status {
+                Ok(message) => text(*message),
+                Err(err) => text(err.clone()),
+            };
+            content = content.push(status_text);
+        }
+
+        content.spacing(10).into()
+    }
+
+    pub fn title() -> String {
+        "Settings".to_owned()
+    }
+
+    pub fn tab_label() -> troxide_widget::tabs::TabLabel {
+        troxide_widget::tabs::TabLabel::new(Self::title(), GEAR_FILL)
+    }
+}
+
+/// Turns a schedule-export result into the status line shown under the export controls
+fn export_schedule(result: Result<(), FeedExportError>) -> Result<&'static str, String> {
+    result
+        .map(|_| "Schedule exported")
+        .map_err(|err| format!("failed to export schedule: {err}"))
+}
+
+/// Turns a library import/export result into the status line shown under the library controls
+fn transfer_library(
+    result: Result<(), ImportExportError>,
+    verb: &'static str,
+) -> Result<&'static str, String> {
+    result
+        .map(|_| match verb {
+            "imported" => "Library imported",
+            _ => "Library exported",
+        })
+        .map_err(|err| format!("failed to transfer library: {err}"))
+}
@@ -1,102 +1,419 @@
-use iced::widget::{column, container, row, scrollable};
-use iced::{Command, Element, Length, Renderer};
+use std::ops::Range;
+
+use iced::futures::channel::mpsc;
+use iced::widget::scrollable::{RelativeOffset, Viewport};
+use iced::widget::{
+    button, column, container, pick_list, row, scrollable, svg, text, text_input, Space,
+};
+use iced::{Element, Length, Task};
 use iced_aw::Wrap;
 
+use crate::core::caching::bulk_refresh;
 use crate::core::{api::series_information::SeriesMainInformation, database};
-use crate::gui::assets::icons::GRAPH_UP_ARROW;
+use crate::gui::assets::icons::{CHEVRON_DOWN, CHEVRON_UP, GRAPH_UP_ARROW};
+use crate::gui::message::IndexedMessage;
+use crate::gui::styles;
 use crate::gui::troxide_widget;
 use series_banner::{Message as SeriesBannerMessage, SeriesBanner};
 
 use mini_widgets::*;
 
 mod mini_widgets;
+mod series_banner;
+
+/// Rough footprint (including `Wrap` spacing) of one banner, used only to estimate which rows of
+/// the grid are currently on screen. Doesn't need to track the real layout pixel-for-pixel: worst
+/// case a slightly-off estimate just over- or under-shoots the overscan by a row.
+const BANNER_WIDTH: f32 = 110.0;
+const BANNER_HEIGHT: f32 = 165.0;
+
+/// Extra rows rendered above and below the visible viewport so a quick scroll doesn't flash
+/// empty placeholders before the next frame fills them in.
+const OVERSCAN_ROWS: usize = 2;
+
+/// How many banners are realized up front, before the first scroll event reports a real
+/// viewport size.
+const INITIAL_BANNER_NUMBER: usize = 40;
+
+/// A field `StatisticsTab` can order its banner grid by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortField {
+    TotalWatchTime,
+    Name,
+    PremiereDate,
+    EpisodesWatched,
+}
+
+impl SortField {
+    const ALL: [Self; 4] = [
+        Self::TotalWatchTime,
+        Self::Name,
+        Self::PremiereDate,
+        Self::EpisodesWatched,
+    ];
+}
+
+impl std::fmt::Display for SortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::TotalWatchTime => "Total watch time",
+            Self::Name => "Name",
+            Self::PremiereDate => "Premiere date",
+            Self::EpisodesWatched => "Episodes watched",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    fn toggled(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// A tracked series together with the statistics `StatisticsTab` can sort and filter it by
+#[derive(Clone)]
+struct SeriesStat {
+    info: SeriesMainInformation,
+    average_watchtime_minutes: u32,
+    episodes_watched: u32,
+}
 
 #[derive(Clone, Debug)]
 pub enum Message {
-    SeriesInfosAndTimeReceived(Vec<(SeriesMainInformation, u32)>),
-    SeriesBanner(usize, SeriesBannerMessage),
+    SeriesInfosAndTimeReceived(Vec<(SeriesMainInformation, u32, u32)>),
+    SeriesBanner(IndexedMessage<usize, SeriesBannerMessage>),
+    BulkRefresh(bulk_refresh::Event),
+    PageScrolled(Viewport),
+    SortChanged(SortField),
+    SortOrderToggled,
+    FilterChanged(String),
 }
 
 #[derive(Default)]
 pub struct StatisticsTab {
-    series_infos_and_time: Vec<(SeriesMainInformation, u32)>,
-    series_banners: Vec<SeriesBanner>,
+    series_stats: Vec<SeriesStat>,
+    /// One slot per entry in `series_stats`, left `None` until its index enters the visible
+    /// window so its poster image isn't fetched before it's ever shown.
+    series_banners: Vec<Option<SeriesBanner>>,
+    /// Indices into `series_stats`/`series_banners`, in the order the grid should currently
+    /// display them. Recomputed from `sort_field`/`sort_order`/`filter_text` without re-fetching
+    /// anything.
+    displayed_order: Vec<usize>,
+    sort_field: SortField,
+    sort_order: SortOrder,
+    filter_text: String,
+    scrollable_offset: RelativeOffset,
+    last_viewport: Option<Viewport>,
+    /// Set once the `bulk_refresh` subscription reports it's ready to take input; `refresh`
+    /// queues a request in `pending_refresh` instead of dropping it if this isn't ready yet
+    refresh_sender: Option<mpsc::Sender<bulk_refresh::Input>>,
+    pending_refresh: Option<Vec<u32>>,
+    /// `(completed, total)` for the refresh currently in flight, shown as "refreshing N/M"
+    refresh_progress: Option<(usize, usize)>,
+}
+
+impl Default for SortField {
+    fn default() -> Self {
+        Self::TotalWatchTime
+    }
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Descending
+    }
 }
 
 impl StatisticsTab {
-    pub fn new() -> (Self, Command<Message>) {
+    pub fn new() -> (Self, Task<Message>) {
         (
             Self::default(),
-            Command::perform(
-                get_series_with_runtime(),
-                Message::SeriesInfosAndTimeReceived,
-            ),
+            Task::perform(get_series_with_runtime(), Message::SeriesInfosAndTimeReceived),
         )
     }
 
-    pub fn update(&mut self, message: Message) -> Command<Message> {
+    /// Refreshes every tracked series' episode list cache through the shared bounded-concurrency
+    /// bulk refresh pipeline, then recomputes the displayed watchtime statistics. If the
+    /// `bulk_refresh` subscription hasn't reported its input channel yet, the request is queued
+    /// in `pending_refresh` and sent as soon as it does.
+    pub fn refresh(&mut self) -> Task<Message> {
+        let series_ids: Vec<u32> = database::DB
+            .get_series_collection()
+            .into_iter()
+            .map(|series| series.id)
+            .collect();
+
+        self.start_refresh(series_ids);
+        Task::none()
+    }
+
+    fn start_refresh(&mut self, series_ids: Vec<u32>) {
+        match &mut self.refresh_sender {
+            Some(sender) => {
+                let total = series_ids.len();
+                if sender
+                    .try_send(bulk_refresh::Input::Refresh(series_ids))
+                    .is_ok()
+                {
+                    self.refresh_progress = Some((0, total));
+                }
+            }
+            None => self.pending_refresh = Some(series_ids),
+        }
+    }
+
+    pub fn subscription(&self) -> iced::Subscription<Message> {
+        let mut subscriptions: Vec<iced::Subscription<Message>> = self
+            .series_banners
+            .iter()
+            .flatten()
+            .map(|banner| banner.subscription().map(Message::SeriesBanner))
+            .collect();
+        subscriptions.push(bulk_refresh::bulk_refresh().map(Message::BulkRefresh));
+        iced::Subscription::batch(subscriptions)
+    }
+
+    /// Recomputes `displayed_order` from the current sort field/order and name filter, without
+    /// touching `series_stats` or any already-realized banner
+    fn recompute_displayed_order(&mut self) {
+        let filter = self.filter_text.to_lowercase();
+
+        let mut order: Vec<usize> = self
+            .series_stats
+            .iter()
+            .enumerate()
+            .filter(|(_, stat)| stat.info.name.to_lowercase().contains(&filter))
+            .map(|(index, _)| index)
+            .collect();
+
+        order.sort_by(|a, b| {
+            let (a, b) = (&self.series_stats[*a], &self.series_stats[*b]);
+            match self.sort_field {
+                SortField::TotalWatchTime => {
+                    a.average_watchtime_minutes.cmp(&b.average_watchtime_minutes)
+                }
+                SortField::Name => a.info.name.cmp(&b.info.name),
+                SortField::PremiereDate => a.info.premiered.cmp(&b.info.premiered),
+                SortField::EpisodesWatched => a.episodes_watched.cmp(&b.episodes_watched),
+            }
+        });
+
+        if let SortOrder::Descending = self.sort_order {
+            order.reverse();
+        }
+
+        self.displayed_order = order;
+        self.last_viewport = None;
+    }
+
+    /// The position range, inclusive of overscan, within `displayed_order` that should have a
+    /// realized banner
+    fn visible_position_range(&self) -> Range<usize> {
+        let total = self.displayed_order.len();
+        if total == 0 {
+            return 0..0;
+        }
+
+        let Some(viewport) = &self.last_viewport else {
+            return 0..INITIAL_BANNER_NUMBER.min(total);
+        };
+
+        let columns = ((viewport.bounds().width / BANNER_WIDTH).floor() as usize).max(1);
+        let rows_on_screen = (viewport.bounds().height / BANNER_HEIGHT).ceil() as usize + 1;
+        let total_rows = total.div_ceil(columns);
+
+        let first_visible_row = (viewport.absolute_offset().y / BANNER_HEIGHT).floor() as usize;
+        let first_row = first_visible_row.saturating_sub(OVERSCAN_ROWS);
+        let last_row = (first_visible_row + rows_on_screen + OVERSCAN_ROWS).min(total_rows);
+
+        (first_row * columns).min(total)..(last_row * columns).min(total)
+    }
+
+    /// Realizes every banner within `visible_position_range` that hasn't been constructed yet,
+    /// returning the batched image-loading commands for the newly created ones
+    fn realize_visible_banners(&mut self) -> Task<Message> {
+        let mut commands = Vec::new();
+        for position in self.visible_position_range() {
+            let index = self.displayed_order[position];
+            if self.series_banners[index].is_none() {
+                let stat = &self.series_stats[index];
+                let (banner, banner_command) = SeriesBanner::new(
+                    index,
+                    (stat.info.clone(), stat.average_watchtime_minutes),
+                );
+                self.series_banners[index] = Some(banner);
+                commands.push(banner_command.map(Message::SeriesBanner));
+            }
+        }
+        Task::batch(commands)
+    }
+
+    pub fn update(&mut self, message: Message) -> Task<Message> {
         match message {
-            Message::SeriesInfosAndTimeReceived(mut series_infos_and_time) => {
-                self.series_infos_and_time = series_infos_and_time.clone();
-
-                series_infos_and_time.sort_by(|(_, average_minutes_a), (_, average_minutes_b)| {
-                    average_minutes_b.cmp(average_minutes_a)
-                });
-
-                let mut banners = Vec::with_capacity(series_infos_and_time.len());
-                let mut banners_commands = Vec::with_capacity(series_infos_and_time.len());
-                for (index, series_info_and_time) in series_infos_and_time.into_iter().enumerate() {
-                    let (banner, banner_command) = SeriesBanner::new(index, series_info_and_time);
-                    banners.push(banner);
-                    banners_commands.push(banner_command);
+            Message::BulkRefresh(event) => match event {
+                bulk_refresh::Event::Ready(mut sender) => {
+                    if let Some(series_ids) = self.pending_refresh.take() {
+                        let total = series_ids.len();
+                        if sender
+                            .try_send(bulk_refresh::Input::Refresh(series_ids))
+                            .is_ok()
+                        {
+                            self.refresh_progress = Some((0, total));
+                        }
+                    }
+                    self.refresh_sender = Some(sender);
+                    Task::none()
+                }
+                bulk_refresh::Event::Progress { completed, total } => {
+                    self.refresh_progress = Some((completed, total));
+                    Task::none()
+                }
+                bulk_refresh::Event::Finished => {
+                    self.refresh_progress = None;
+                    Task::perform(get_series_with_runtime(), Message::SeriesInfosAndTimeReceived)
                 }
-                self.series_banners = banners;
-                Command::batch(banners_commands)
-                    .map(|message| Message::SeriesBanner(message.get_id(), message))
+            },
+            Message::SeriesInfosAndTimeReceived(series_infos_and_time) => {
+                self.series_stats = series_infos_and_time
+                    .into_iter()
+                    .map(|(info, average_watchtime_minutes, episodes_watched)| SeriesStat {
+                        info,
+                        average_watchtime_minutes,
+                        episodes_watched,
+                    })
+                    .collect();
+                self.series_banners = vec![None; self.series_stats.len()];
+
+                self.recompute_displayed_order();
+                self.realize_visible_banners()
+            }
+            Message::SeriesBanner(message) => {
+                if let Some(banner) = &mut self.series_banners[message.index()] {
+                    banner.update(message).map(Message::SeriesBanner)
+                } else {
+                    Task::none()
+                }
+            }
+            Message::PageScrolled(viewport) => {
+                self.scrollable_offset = viewport.relative_offset();
+                self.last_viewport = Some(viewport);
+                self.realize_visible_banners()
+            }
+            Message::SortChanged(sort_field) => {
+                self.sort_field = sort_field;
+                self.recompute_displayed_order();
+                self.realize_visible_banners()
             }
-            Message::SeriesBanner(index, message) => {
-                self.series_banners[index].update(message);
-                Command::none()
+            Message::SortOrderToggled => {
+                self.sort_order = self.sort_order.toggled();
+                self.recompute_displayed_order();
+                self.realize_visible_banners()
+            }
+            Message::FilterChanged(filter_text) => {
+                self.filter_text = filter_text;
+                self.recompute_displayed_order();
+                self.realize_visible_banners()
             }
         }
     }
-    pub fn view(&self) -> Element<Message, Renderer> {
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let visible_range = self.visible_position_range();
+
         let series_list = Wrap::with_elements(
-            self.series_banners
+            self.displayed_order
                 .iter()
-                .map(|banner| {
-                    banner
-                        .view()
-                        .map(|message| Message::SeriesBanner(message.get_id(), message))
+                .enumerate()
+                .map(|(position, index)| match &self.series_banners[*index] {
+                    Some(banner) if visible_range.contains(&position) => {
+                        banner.view().map(Message::SeriesBanner)
+                    }
+                    _ => Space::new(BANNER_WIDTH, BANNER_HEIGHT).into(),
                 })
                 .collect(),
         )
         .spacing(5.0)
         .line_spacing(5.0);
 
-        let series_list = container(series_list).width(Length::Fill).center_x();
+        let series_list = container(series_list).width(Length::Fill).center_x(Length::Fill);
+
+        let sort_order_icon_handle = svg::Handle::from_memory(match self.sort_order {
+            SortOrder::Ascending => CHEVRON_UP,
+            SortOrder::Descending => CHEVRON_DOWN,
+        });
+        let sort_order_button = button(
+            svg(sort_order_icon_handle)
+                .width(20)
+                .height(20)
+                .style(styles::svg_styles::colored_svg_theme),
+        )
+        .style(styles::button_styles::transparent_button_theme)
+        .on_press(Message::SortOrderToggled);
 
-        let content = column![
-            row![watch_count(), time_count(&self.series_infos_and_time)].spacing(10),
-            series_list
+        let controls = row![
+            pick_list(SortField::ALL, Some(self.sort_field), Message::SortChanged),
+            sort_order_button,
+            text_input("Filter by name", &self.filter_text).on_input(Message::FilterChanged),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+
+        let watchtime_totals: Vec<(SeriesMainInformation, u32)> = self
+            .series_stats
+            .iter()
+            .map(|stat| (stat.info.clone(), stat.average_watchtime_minutes))
+            .collect();
+
+        let mut content = column![
+            row![watch_count(), time_count(&watchtime_totals)].spacing(10),
+            controls,
         ]
         .spacing(10)
         .padding(10);
 
-        container(scrollable(content))
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+        if let Some((completed, total)) = self.refresh_progress {
+            content = content.push(text(format!("Refreshing {completed}/{total}")).size(11));
+        }
+
+        let content = content.push(series_list);
+
+        container(
+            scrollable(content)
+                .on_scroll(Message::PageScrolled)
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
     }
 }
 
-/// Get the collection of all series with their associated total
-/// average runtime
-async fn get_series_with_runtime() -> Vec<(SeriesMainInformation, u32)> {
+/// Get the collection of all series with their associated total average runtime and the number
+/// of episodes watched
+async fn get_series_with_runtime() -> Vec<(SeriesMainInformation, u32, u32)> {
     let series_ids_handles: Vec<_> = database::DB
         .get_series_collection()
         .into_iter()
-        .map(|series| tokio::spawn(async move { series.get_total_average_watchtime().await }))
+        .map(|series| {
+            tokio::spawn(async move {
+                let episodes_watched = series.get_total_episodes_watched();
+                series
+                    .get_total_average_watchtime()
+                    .await
+                    .map(|(info, average_minutes)| (info, average_minutes, episodes_watched))
+            })
+        })
         .collect();
 
     let mut infos_and_time = Vec::with_capacity(series_ids_handles.len());
@@ -119,4 +436,4 @@ impl StatisticsTab {
     pub fn tab_label() -> troxide_widget::tabs::TabLabel {
         troxide_widget::tabs::TabLabel::new(Self::title(), GRAPH_UP_ARROW)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,151 @@
+//! The per-series tile shown in `StatisticsTab`'s banner grid: a poster image annotated with the
+//! series' average watch time, with a right-click menu to copy its title, open it on TVmaze or
+//! remove it from the tracked series. The menu has no entry to open the series page or to hide
+//! the series from Discover, and a plain click on the banner does nothing.
+
+use bytes::Bytes;
+use iced::widget::{column, container, image, mouse_area, text};
+use iced::{Element, Point, Task};
+
+use crate::core::api::series_information::SeriesMainInformation;
+use crate::core::{caching, database};
+use crate::gui::helpers;
+use crate::gui::message::IndexedMessage;
+use crate::gui::styles;
+use crate::gui::troxide_widget::context_menu::{ContextMenu, MenuAction};
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    ImageLoaded(Option<Bytes>),
+    RemoveFromTracked,
+    CopyTitle,
+    OpenOnTvMaze,
+    OpenContextMenu,
+    ContextMenuMoved(Point),
+    CloseContextMenu,
+}
+
+pub struct SeriesBanner {
+    index: usize,
+    series_info: SeriesMainInformation,
+    average_watchtime_minutes: u32,
+    image: Option<Bytes>,
+    context_menu: ContextMenu,
+}
+
+impl SeriesBanner {
+    pub fn new(
+        index: usize,
+        (series_info, average_watchtime_minutes): (SeriesMainInformation, u32),
+    ) -> (Self, Task<IndexedMessage<usize, Message>>) {
+        let image_info = series_info.image.clone();
+
+        let banner = Self {
+            index,
+            series_info,
+            average_watchtime_minutes,
+            image: None,
+            context_menu: ContextMenu::new(),
+        };
+
+        let command = Task::perform(
+            async move {
+                match image_info {
+                    Some(image) => {
+                        caching::load_image(image.medium_image_url, caching::ImageResolution::Medium)
+                            .await
+                    }
+                    None => None,
+                }
+            },
+            Message::ImageLoaded,
+        )
+        .map(move |message| IndexedMessage::new(index, message));
+
+        (banner, command)
+    }
+
+    /// Closes the context menu on `Escape`; batch this into the owning view's subscription
+    pub fn subscription(&self) -> iced::Subscription<IndexedMessage<usize, Message>> {
+        let index = self.index;
+        self.context_menu
+            .subscription(Message::CloseContextMenu)
+            .map(move |message| IndexedMessage::new(index, message))
+    }
+
+    pub fn update(
+        &mut self,
+        message: IndexedMessage<usize, Message>,
+    ) -> Task<IndexedMessage<usize, Message>> {
+        let index = self.index;
+        match message.message() {
+            Message::ImageLoaded(image) => self.image = image,
+            Message::OpenContextMenu => self.context_menu.open(),
+            Message::ContextMenuMoved(position) => self.context_menu.track_cursor(position),
+            Message::CloseContextMenu => self.context_menu.close(),
+            Message::RemoveFromTracked => {
+                database::DB.remove_series(self.series_info.id);
+            }
+            Message::CopyTitle => {
+                return iced::clipboard::write(self.series_info.name.clone())
+                    .map(move |message| IndexedMessage::new(index, message));
+            }
+            Message::OpenOnTvMaze => {
+                if let Err(err) = webbrowser::open(&self.series_info.url) {
+                    tracing::warn!("failed to open series page in browser: {}", err);
+                }
+            }
+        }
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let poster_image: Element<'_, Message> = if let Some(image_bytes) = &self.image {
+            let image_handle = image::Handle::from_bytes(image_bytes.clone());
+            image(image_handle).height(140).into()
+        } else {
+            helpers::empty_image::empty_image()
+                .width(100)
+                .height(140)
+                .into()
+        };
+
+        let content = column![
+            poster_image,
+            text(&self.series_info.name)
+                .size(11)
+                .width(100)
+                .height(30)
+                .align_x(iced::Alignment::Center)
+                .align_y(iced::Alignment::Center),
+            text(Self::average_watchtime_label(self.average_watchtime_minutes)).size(11),
+        ]
+        .padding(2)
+        .spacing(1);
+
+        let content = container(content)
+            .padding(5)
+            .style(styles::container_styles::second_class_container_rounded_theme);
+
+        let mouse_area = mouse_area(content);
+
+        self.context_menu.view(
+            mouse_area,
+            Message::OpenContextMenu,
+            Message::ContextMenuMoved,
+            Message::CloseContextMenu,
+            vec![
+                MenuAction::new("Copy title", Message::CopyTitle),
+                MenuAction::new("Open on TVmaze", Message::OpenOnTvMaze),
+                MenuAction::new("Remove from tracked", Message::RemoveFromTracked),
+            ],
+        )
+    }
+
+    fn average_watchtime_label(average_watchtime_minutes: u32) -> String {
+        match helpers::time::NaiveTime::new(average_watchtime_minutes).largest_part() {
+            Some((value, unit)) => format!("Avg: {} {}", value, unit),
+            None => "Avg: 0m".to_owned(),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+pub mod context_menu;
+
 pub mod episode_widget {
     use crate::core::{
         api::tv_maze::episodes_information::Episode as EpisodeInfo, caching, database,
@@ -8,16 +10,19 @@ pub mod episode_widget {
     use crate::gui::styles;
     use bytes::Bytes;
     use iced::font::Weight;
-    use iced::widget::{
-        button, checkbox, column, container, image, row, svg, text, Row, Space, Text,
-    };
+    use iced::widget::markdown;
+    use iced::widget::{button, checkbox, column, container, image, row, svg, text, Row, Space};
     use iced::{Element, Font, Length, Task};
+    use url::Url;
 
     #[derive(Clone, Debug)]
     pub enum Message {
         ImageLoaded(Option<Bytes>),
         MarkedWatched(PosterType),
         TrackTaskComplete(bool),
+        LinkClicked(Url),
+        /// Forces a redraw so `date_time_widget`'s countdown stays current
+        Tick,
     }
 
     #[derive(Clone, Copy, Debug)]
@@ -34,6 +39,9 @@ pub mod episode_widget {
         series_id: u32,
         episode_image: Option<Bytes>,
         set_watched: bool,
+        /// The summary, pre-parsed into markdown items so `view` doesn't redo the html-to-markdown
+        /// pass and reparse on every redraw
+        summary_items: Vec<markdown::Item>,
     }
 
     impl Episode {
@@ -44,6 +52,7 @@ pub mod episode_widget {
             episode_information: EpisodeInfo,
         ) -> (Self, Task<IndexedMessage<usize, Message>>) {
             let episode_image = episode_information.image.clone();
+            let summary_items = parse_summary(episode_information.summary.as_deref());
             let episode = Self {
                 index,
                 series_name,
@@ -51,6 +60,7 @@ pub mod episode_widget {
                 series_id,
                 episode_image: None,
                 set_watched: false,
+                summary_items,
             };
 
             let command = if let Some(image) = episode_image {
@@ -70,6 +80,14 @@ pub mod episode_widget {
             self.set_watched
         }
 
+        /// Ticks once a minute so the relative countdown in `date_time_widget` keeps counting
+        /// down without the user having to re-navigate to refresh it
+        pub fn subscription(&self) -> iced::Subscription<IndexedMessage<usize, Message>> {
+            let index = self.index;
+            iced::time::every(std::time::Duration::from_secs(60))
+                .map(move |_| IndexedMessage::new(index, Message::Tick))
+        }
+
         pub fn update(
             &mut self,
             message: IndexedMessage<usize, Message>,
@@ -123,6 +141,13 @@ pub mod episode_widget {
                     }
                     Task::none()
                 }
+                Message::LinkClicked(url) => {
+                    if let Err(err) = webbrowser::open(url.as_str()) {
+                        tracing::warn!("failed to open summary link in browser: {}", err);
+                    }
+                    Task::none()
+                }
+                Message::Tick => Task::none(),
             }
         }
 
@@ -150,7 +175,7 @@ pub mod episode_widget {
                 heading_widget(self.series_id, &self.episode_information, poster_type),
                 date_time_widget(&self.episode_information),
                 Space::with_height(5),
-                summary_widget(&self.episode_information)
+                summary_widget(&self.episode_information, &self.summary_items)
             );
 
             let content = content.push(episode_details);
@@ -168,22 +193,48 @@ pub mod episode_widget {
         }
     }
 
-    fn summary_widget(episode_information: &EpisodeInfo) -> Text<'static> {
-        if let Some(summary) = &episode_information.summary {
+    /// Parses an episode's HTML summary into markdown items once, up front, so `view` only has to
+    /// render them. Returns an empty list (rendered as the plain-text path in [`summary_widget`])
+    /// when there is no summary or the converted markdown yields no items; the conversion itself
+    /// reports no errors, so a summary that converts badly is rendered as whatever it produced.
+    fn parse_summary(summary: Option<&str>) -> Vec<markdown::Item> {
+        let Some(summary) = summary else {
+            return Vec::new();
+        };
+
+        let markdown_text = html2md::parse_html(summary);
+        markdown::parse(&markdown_text).collect()
+    }
+
+    fn summary_widget<'a>(
+        episode_information: &'a EpisodeInfo,
+        summary_items: &'a [markdown::Item],
+    ) -> Element<'a, Message> {
+        if !summary_items.is_empty() {
+            markdown::view(
+                summary_items,
+                markdown::Settings::default(),
+                Message::LinkClicked,
+            )
+            .into()
+        } else if let Some(summary) = &episode_information.summary {
             let summary = html2text::from_read(summary.as_bytes(), 1000).unwrap_or_default();
-            text(summary).size(11)
+            text(summary).size(11).into()
         } else {
-            text("")
+            text("").into()
         }
     }
 
     fn date_time_widget(episode_information: &EpisodeInfo) -> Element<'_, Message> {
         if let Ok(release_time) = episode_information.release_time() {
-            let prefix = match release_time.is_future() {
-                true => "Airing on",
-                false => "Aired on",
-            };
-            text(format!("{} {}", prefix, release_time)).into()
+            if release_time.is_future() {
+                let countdown = release_time
+                    .get_remaining_release_time()
+                    .unwrap_or_else(|| "Now".to_owned());
+                text(format!("Airs in {}", countdown)).into()
+            } else {
+                text(format!("Aired on {}", release_time)).into()
+            }
         } else {
             Space::new(0, 0).into()
         }
@@ -253,16 +304,21 @@ pub mod series_poster {
     use crate::core::api::tv_maze::series_information::{Rating, SeriesMainInformation};
     use crate::core::api::tv_maze::Image;
     use crate::core::caching;
+    use crate::core::database;
     use crate::core::posters_hiding::HIDDEN_SERIES;
     use crate::gui::assets::icons::{EYE_SLASH_FILL, STAR_FILL};
     use crate::gui::helpers;
     pub use crate::gui::message::IndexedMessage;
     use crate::gui::styles;
+    use crate::gui::troxide_widget::context_menu::{ContextMenu, MenuAction};
 
     use bytes::Bytes;
     use iced::font::Weight;
     use iced::widget::{button, column, container, image, mouse_area, row, svg, text, Space};
-    use iced::{Element, Font, Task};
+    use iced::{Element, Font, Point, Task};
+
+    /// How long the "Hidden — Undo" toast stays up before the hide becomes permanent
+    const UNDO_TOAST_DURATION: std::time::Duration = std::time::Duration::from_secs(5);
 
     #[derive(Debug, Clone)]
     pub enum GenericPosterMessage {
@@ -272,23 +328,26 @@ pub mod series_poster {
     pub struct GenericPoster<'a> {
         series_information: Cow<'a, SeriesMainInformation>,
         image: Option<Bytes>,
+        /// Whether `request_image` has already fired a load for this poster, so a parent
+        /// scrollable can call it on every poster in the visible range without refetching
+        /// already-loading/loaded images
+        image_requested: bool,
         series_page_sender: mpsc::Sender<SeriesMainInformation>,
     }
 
     impl<'a> GenericPoster<'a> {
+        /// Starts in an "image not requested" state; the caller must call [`Self::request_image`]
+        /// once this poster is within (or near) the viewport
         pub fn new(
             series_information: Cow<'a, SeriesMainInformation>,
             series_page_sender: mpsc::Sender<SeriesMainInformation>,
-        ) -> (Self, Task<GenericPosterMessage>) {
-            let image_url = series_information.image.clone();
-
-            let poster = Self {
+        ) -> Self {
+            Self {
                 series_information,
                 image: None,
+                image_requested: false,
                 series_page_sender,
-            };
-
-            (poster, Self::load_image(image_url))
+            }
         }
 
         pub fn update(&mut self, message: GenericPosterMessage) {
@@ -312,6 +371,23 @@ pub mod series_poster {
             self.image.as_ref()
         }
 
+        /// Starts loading the poster image if it hasn't been requested yet; a no-op otherwise
+        pub fn request_image(&mut self) -> Task<GenericPosterMessage> {
+            if self.image_requested {
+                return Task::none();
+            }
+            self.image_requested = true;
+            Self::load_image(self.series_information.image.clone())
+        }
+
+        /// Drops the decoded image once this poster scrolls far enough off-screen, so a long
+        /// scroll through a big grid doesn't keep every image it has ever shown resident in
+        /// memory. A later [`Self::request_image`] call re-fetches it.
+        pub fn forget_image(&mut self) {
+            self.image = None;
+            self.image_requested = false;
+        }
+
         fn load_image(image: Option<Image>) -> Task<GenericPosterMessage> {
             if let Some(image) = image {
                 Task::perform(
@@ -337,6 +413,17 @@ pub mod series_poster {
         Expand,
         Hide,
         SeriesHidden,
+        UndoHide,
+        HideUndone,
+        ToastExpired,
+        RemoveFromTracked,
+        CopyTitle,
+        OpenOnTvMaze,
+        OpenContextMenu,
+        ContextMenuMoved(Point),
+        CloseContextMenu,
+        /// Sent by the parent scrollable once this poster enters (or nears) the viewport
+        BecameVisible,
     }
 
     pub struct SeriesPoster<'a> {
@@ -344,35 +431,59 @@ pub mod series_poster {
         poster: GenericPoster<'a>,
         expanded: bool,
         hidden: bool,
+        /// `true` while the "Hidden — Undo" toast is showing for this poster; cleared either by
+        /// [`Message::UndoHide`] or by the toast's own timeout ([`Message::ToastExpired`])
+        pending_undo: bool,
+        context_menu: ContextMenu,
     }
 
     impl<'a> SeriesPoster<'a> {
+        /// Starts with its image not yet requested; the parent scrollable must call
+        /// [`Self::request_image`] (or route in a [`Message::BecameVisible`]) once this poster is
+        /// within (or near) the viewport
         pub fn new(
             index: usize,
             series_information: Cow<'a, SeriesMainInformation>,
             series_page_sender: mpsc::Sender<SeriesMainInformation>,
-        ) -> (Self, Task<IndexedMessage<usize, Message>>) {
-            let (poster, poster_command) =
-                GenericPoster::new(series_information, series_page_sender);
-            let poster = Self {
+        ) -> Self {
+            let poster = GenericPoster::new(series_information, series_page_sender);
+            Self {
                 index,
                 poster,
                 expanded: false,
                 hidden: false,
-            };
-
-            (
-                poster,
-                poster_command
-                    .map(Message::Poster)
-                    .map(move |message| IndexedMessage::new(index, message)),
-            )
+                pending_undo: false,
+                context_menu: ContextMenu::new(),
+            }
         }
 
         pub fn get_series_info(&self) -> &SeriesMainInformation {
             self.poster.get_series_info()
         }
 
+        /// Starts loading this poster's image if it hasn't been requested yet; called by the
+        /// parent scrollable for every poster currently in (or near) the visible range
+        pub fn request_image(&mut self) -> Task<IndexedMessage<usize, Message>> {
+            let index = self.index;
+            self.poster
+                .request_image()
+                .map(Message::Poster)
+                .map(move |message| IndexedMessage::new(index, message))
+        }
+
+        /// Drops this poster's decoded image once it scrolls far enough off-screen
+        pub fn forget_image(&mut self) {
+            self.poster.forget_image();
+        }
+
+        /// Closes the context menu on `Escape`; batch this into the owning view's subscription
+        pub fn subscription(&self) -> iced::Subscription<IndexedMessage<usize, Message>> {
+            let index = self.index;
+            self.context_menu
+                .subscription(Message::CloseContextMenu)
+                .map(move |message| IndexedMessage::new(index, message))
+        }
+
         pub fn update(
             &mut self,
             message: IndexedMessage<usize, Message>,
@@ -382,6 +493,20 @@ pub mod series_poster {
                     self.poster.open_series_page();
                 }
                 Message::Expand => self.expanded = !self.expanded,
+                Message::OpenContextMenu => self.context_menu.open(),
+                Message::ContextMenuMoved(position) => self.context_menu.track_cursor(position),
+                Message::CloseContextMenu => self.context_menu.close(),
+                Message::RemoveFromTracked => {
+                    database::DB.remove_series(self.poster.get_series_info().id);
+                }
+                Message::CopyTitle => {
+                    return iced::clipboard::write(self.poster.get_series_info().name.clone());
+                }
+                Message::OpenOnTvMaze => {
+                    if let Err(err) = webbrowser::open(&self.poster.get_series_info().url) {
+                        tracing::warn!("failed to open series page in browser: {}", err);
+                    }
+                }
                 Message::Hide => {
                     let series_id = self.poster.get_series_info().id;
                     let index = self.index;
@@ -402,8 +527,37 @@ pub mod series_poster {
                 }
                 Message::SeriesHidden => {
                     self.hidden = true;
+                    self.pending_undo = true;
+
+                    let index = self.index;
+                    return Task::perform(
+                        tokio::time::sleep(UNDO_TOAST_DURATION),
+                        |_| Message::ToastExpired,
+                    )
+                    .map(move |message| IndexedMessage::new(index, message));
+                }
+                Message::ToastExpired => {
+                    self.pending_undo = false;
+                }
+                Message::UndoHide => {
+                    let series_id = self.poster.get_series_info().id;
+                    let index = self.index;
+
+                    return Task::perform(
+                        async move {
+                            let mut hidden_series = HIDDEN_SERIES.write().await;
+                            hidden_series.unhide_series(series_id).await
+                        },
+                        |_| Message::HideUndone,
+                    )
+                    .map(move |message| IndexedMessage::new(index, message));
+                }
+                Message::HideUndone => {
+                    self.hidden = false;
+                    self.pending_undo = false;
                 }
                 Message::Poster(message) => self.poster.update(message),
+                Message::BecameVisible => return self.request_image(),
             }
             Task::none()
         }
@@ -413,6 +567,15 @@ pub mod series_poster {
         }
 
         pub fn view(&self, expandable: bool) -> Element<'_, IndexedMessage<usize, Message>> {
+            if self.pending_undo {
+                return self
+                    .toast_view()
+                    .map(|message| IndexedMessage::new(self.index, message));
+            }
+            if self.hidden {
+                return Space::new(0, 0).into();
+            }
+
             let poster_image: Element<'_, Message> = {
                 let image_height = if self.expanded { 170 } else { 140 };
                 if let Some(image_bytes) = self.poster.get_image() {
@@ -468,11 +631,26 @@ pub mod series_poster {
 
             let mut mouse_area = mouse_area(content).on_press(Message::SeriesPosterPressed);
 
-            if expandable {
+            let element: Element<'_, Message> = if expandable {
                 mouse_area = mouse_area.on_right_press(Message::Expand);
-            }
-
-            let element: Element<'_, Message> = mouse_area.into();
+                mouse_area.into()
+            } else {
+                // Expandable posters already spend their right-click on `Expand`; everywhere else
+                // (e.g. a person's cast credits) it's free for the context menu instead.
+                self.context_menu.view(
+                    mouse_area,
+                    Message::OpenContextMenu,
+                    Message::ContextMenuMoved,
+                    Message::CloseContextMenu,
+                    vec![
+                        MenuAction::new("Open series page", Message::SeriesPosterPressed),
+                        MenuAction::new("Copy title", Message::CopyTitle),
+                        MenuAction::new("Open on TVmaze", Message::OpenOnTvMaze),
+                        MenuAction::new("Remove from tracked", Message::RemoveFromTracked),
+                        MenuAction::new("Hide from Discover", Message::Hide),
+                    ],
+                )
+            };
             element.map(|message| IndexedMessage::new(self.index, message))
         }
 
@@ -508,6 +686,28 @@ pub mod series_poster {
             }
         }
 
+        /// The transient "Hidden {name} — Undo" overlay shown in place of the poster right after
+        /// [`Message::Hide`] completes, for as long as [`Self::pending_undo`] stays set
+        fn toast_view(&self) -> Element<'_, Message> {
+            let message = text(format!(
+                "Hidden {} — Undo",
+                self.poster.get_series_info().name
+            ))
+            .size(11);
+
+            let undo_button = button(text("Undo").size(11))
+                .on_press(Message::UndoHide)
+                .style(styles::button_styles::transparent_button_with_rounded_border_theme);
+
+            container(column![message, undo_button].spacing(5).padding(2))
+                .padding(5)
+                .width(100)
+                .height(170)
+                .align_y(iced::Alignment::Center)
+                .style(styles::container_styles::second_class_container_rounded_theme)
+                .into()
+        }
+
         fn hiding_button() -> Element<'static, Message> {
             let tracked_icon_handle = svg::Handle::from_memory(EYE_SLASH_FILL);
             let icon = svg(tracked_icon_handle)
@@ -527,11 +727,11 @@ pub mod series_poster {
 
 pub mod title_bar {
     use iced::widget::{
-        button, container, horizontal_space, mouse_area, row, svg, text, Row, Space,
+        button, container, horizontal_space, mouse_area, row, svg, text, text_input, Row, Space,
     };
-    use iced::{Element, Length};
+    use iced::{Element, Length, Task};
 
-    use crate::gui::assets::icons::CARET_LEFT_FILL;
+    use crate::gui::assets::icons::{CARET_LEFT_FILL, SEARCH, X_LG};
     use crate::gui::styles;
     use crate::gui::tabs::TabLabel;
 
@@ -539,25 +739,60 @@ pub mod title_bar {
     pub enum Message {
         TabSelected(usize),
         BackButtonPressed,
+        SearchOpened,
+        SearchClosed,
+        SearchInput(String),
+        SearchSubmitted(String),
     }
 
     pub struct TitleBar {
         active_tab: usize,
+        /// `Some` (even if empty) while the search field is expanded; `None` while it's
+        /// collapsed back down to just the search icon
+        search_query: Option<String>,
     }
 
     impl TitleBar {
         pub fn new() -> Self {
             Self {
                 active_tab: usize::default(),
+                search_query: None,
             }
         }
 
-        pub fn update(&mut self, message: Message) {
-            if let Message::TabSelected(new_active_tab) = message {
-                self.active_tab = new_active_tab
+        fn search_input_id() -> text_input::Id {
+            text_input::Id::new("title-bar-search-input")
+        }
+
+        /// Updates the active tab and the search field's state; a [`Message::SearchSubmitted`]
+        /// changes nothing here and is left for the hosting view to route to the discover/search tab
+        pub fn update(&mut self, message: Message) -> Task<Message> {
+            match message {
+                Message::TabSelected(new_active_tab) => {
+                    self.active_tab = new_active_tab;
+                    Task::none()
+                }
+                Message::BackButtonPressed => Task::none(),
+                Message::SearchOpened => {
+                    self.search_query = Some(String::new());
+                    text_input::focus(Self::search_input_id())
+                }
+                Message::SearchClosed => {
+                    self.search_query = None;
+                    Task::none()
+                }
+                Message::SearchInput(query) => {
+                    self.search_query = Some(query);
+                    Task::none()
+                }
+                // Handled by the hosting view, which routes the query to the discover tab's
+                // search; `TitleBar` just keeps the field populated with what was submitted
+                Message::SearchSubmitted(_) => Task::none(),
             }
         }
 
+        /// Renders the tab row, plus a search affordance that expands in place into a `text_input`
+        /// when activated, staying reachable no matter which tab is active
         pub fn view(
             &self,
             tab_labels: &[TabLabel],
@@ -597,11 +832,41 @@ pub mod title_bar {
                 Space::new(0, 0).into()
             };
 
+            let search_slot: Element<'_, Message> = if let Some(query) = &self.search_query {
+                let close_icon = svg(svg::Handle::from_memory(X_LG))
+                    .width(16)
+                    .style(styles::svg_styles::colored_svg_theme);
+                let close_button = button(close_icon)
+                    .style(styles::button_styles::transparent_button_theme)
+                    .on_press(Message::SearchClosed);
+
+                row![
+                    text_input("Search...", query)
+                        .id(Self::search_input_id())
+                        .on_input(Message::SearchInput)
+                        .on_submit(Message::SearchSubmitted(query.clone()))
+                        .width(200),
+                    close_button
+                ]
+                .spacing(5)
+                .align_y(iced::Alignment::Center)
+                .into()
+            } else {
+                let search_icon = svg(svg::Handle::from_memory(SEARCH))
+                    .width(18)
+                    .style(styles::svg_styles::colored_svg_theme);
+                button(search_icon)
+                    .style(styles::button_styles::transparent_button_theme)
+                    .on_press(Message::SearchOpened)
+                    .into()
+            };
+
             container(row![
                 back_button,
                 horizontal_space(),
                 tab_views,
-                horizontal_space()
+                horizontal_space(),
+                search_slot,
             ])
             .style(styles::container_styles::first_class_container_square_theme)
             .into()
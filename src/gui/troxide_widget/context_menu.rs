@@ -0,0 +1,125 @@
+//! A reusable right-click action menu that can wrap any element, used by `CastPoster` and
+//! `SeriesPoster` to expose a richer set of per-item actions than a single button press.
+//!
+//! [`ContextMenu`] only owns its own open/closed state; every message it produces (opening,
+//! closing, or picking an entry) is one the caller already defines on its own `Message` type, so
+//! wiring it in is just a few extra match arms rather than a whole new message type to thread
+//! through.
+
+use iced::widget::{button, container, mouse_area, stack, text, Column, Space};
+use iced::{Element, Length, Padding, Point};
+
+use crate::gui::styles;
+
+/// One labelled entry in a [`ContextMenu`], carrying the caller's own message for when it's
+/// picked
+pub struct MenuAction<Message> {
+    label: &'static str,
+    message: Message,
+}
+
+impl<Message> MenuAction<Message> {
+    pub fn new(label: &'static str, message: Message) -> Self {
+        Self { label, message }
+    }
+}
+
+#[derive(Default)]
+pub struct ContextMenu {
+    is_open: bool,
+    /// Where the pointer last was over the wrapped content, so the menu can open under it rather
+    /// than centered over the whole element
+    cursor_position: Point,
+}
+
+impl ContextMenu {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            cursor_position: Point::ORIGIN,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Records the pointer's current position over the wrapped content; the caller should wire
+    /// this to the `on_move` message passed into [`Self::view`]
+    pub fn track_cursor(&mut self, position: Point) {
+        self.cursor_position = position;
+    }
+
+    /// Closes the menu on `Escape`; batch this into the owning widget's own subscription
+    /// alongside its other listeners
+    pub fn subscription<Message: Clone + 'static>(
+        &self,
+        on_close: Message,
+    ) -> iced::Subscription<Message> {
+        if !self.is_open {
+            return iced::Subscription::none();
+        }
+
+        iced::keyboard::on_key_press(move |key, _modifiers| {
+            (key == iced::keyboard::key::Key::Named(iced::keyboard::key::Named::Escape))
+                .then(|| on_close.clone())
+        })
+    }
+
+    /// Wraps `content`, overlaying `actions` when it's been right-clicked (`on_open`/`on_close`
+    /// are the caller's own messages for opening and dismissing the menu, the latter also fired
+    /// by a click anywhere on the wrapped element outside the menu; `on_move` should route to
+    /// [`Self::track_cursor`] so the menu knows where to open). Picking an action only emits that
+    /// action's message and leaves the menu open until the caller calls [`Self::close`].
+    /// Clicks outside the wrapped element's bounds do not close the menu.
+    pub fn view<'a, Message: Clone + 'a>(
+        &self,
+        content: impl Into<Element<'a, Message>>,
+        on_open: Message,
+        on_move: impl Fn(Point) -> Message + 'a,
+        on_close: Message,
+        actions: Vec<MenuAction<Message>>,
+    ) -> Element<'a, Message> {
+        let content: Element<'a, Message> = mouse_area(content)
+            .on_right_press(on_open)
+            .on_move(on_move)
+            .into();
+
+        if !self.is_open {
+            return content;
+        }
+
+        let mut menu_list = Column::new().width(180);
+        for entry in actions {
+            menu_list = menu_list.push(
+                button(text(entry.label))
+                    .width(Length::Fill)
+                    .style(styles::button_styles::transparent_button_theme)
+                    .on_press(entry.message),
+            );
+        }
+
+        let menu = container(menu_list)
+            .style(styles::container_styles::first_class_container_theme)
+            .padding(5);
+
+        // A full-bleed, invisible sensor behind the menu so a click anywhere on the wrapped
+        // element outside the menu closes it rather than falling through to the content underneath
+        let outside_sensor =
+            mouse_area(Space::new(Length::Fill, Length::Fill)).on_press(on_close);
+
+        // Opens under the last tracked pointer position rather than centered over the whole
+        // element
+        let menu = container(menu).padding(Padding {
+            top: self.cursor_position.y,
+            left: self.cursor_position.x,
+            ..Padding::ZERO
+        });
+
+        stack([content, outside_sensor.into(), menu.into()]).into()
+    }
+}